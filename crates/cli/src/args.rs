@@ -1,6 +1,8 @@
 use clap::builder::ValueParser;
 use std::path::{Path, PathBuf};
 
+use crate::report::FailOn;
+
 /// The dependencies checker CLI arguments.
 #[derive(Debug, clap::Parser)]
 #[clap(bin_name = "depcheck-rs")]
@@ -50,6 +52,62 @@ pub struct Args {
     #[clap(value_parser)]
     pub ignore_matches: Option<Vec<String>>,
 
+    /// A flag to indicate if depcheck parses every JavaScript file as Flow typed.
+    #[clap(long = "flow")]
+    #[clap(
+        help = "A flag to indicate if depcheck parses every JavaScript file as Flow typed, not only files with a @flow pragma"
+    )]
+    #[clap(value_parser)]
+    pub flow: bool,
+
+    /// The Node version or range core modules are checked against.
+    #[clap(long = "node-version")]
+    #[clap(
+        help = "The Node version or range core modules are checked against, defaults to engines.node of package.json"
+    )]
+    #[clap(takes_value = true)]
+    #[clap(value_parser)]
+    pub node_version: Option<String>,
+
+    /// A flag to indicate if depcheck checks every member of the package workspaces.
+    #[clap(long = "workspaces")]
+    #[clap(
+        help = "A flag to indicate if depcheck checks every member of npm, yarn or pnpm workspaces"
+    )]
+    #[clap(value_parser)]
+    pub workspaces: bool,
+
+    /// A flag to indicate if depcheck prints the result as JSON.
+    #[clap(long = "json")]
+    #[clap(help = "A flag to indicate if depcheck prints the result as JSON")]
+    #[clap(value_parser)]
+    pub json: bool,
+
+    /// A flag to indicate if depcheck prints the result as SARIF.
+    #[clap(long = "sarif")]
+    #[clap(conflicts_with = "json")]
+    #[clap(
+        help = "A flag to indicate if depcheck prints the result as SARIF 2.1.0 for code scanning"
+    )]
+    #[clap(value_parser)]
+    pub sarif: bool,
+
+    /// Path to a file to write the JSON or SARIF result to instead of printing it.
+    #[clap(long = "output", short = 'o')]
+    #[clap(help = "Path to a file to write the JSON or SARIF result to instead of printing it")]
+    #[clap(takes_value = true)]
+    #[clap(value_parser = ValueParser::os_string())]
+    pub output: Option<PathBuf>,
+
+    /// Comma separated categories of issues depcheck exits with a failure code for.
+    #[clap(long = "fail-on")]
+    #[clap(
+        help = "Comma separated categories of issues depcheck exits with a failure code for, defaults to every category, `none` to always succeed"
+    )]
+    #[clap(use_value_delimiter = true)]
+    #[clap(value_enum)]
+    pub fail_on: Option<Vec<FailOn>>,
+
     /// logging level
     #[clap(flatten)]
     pub verbose: clap_verbosity_flag::Verbosity,
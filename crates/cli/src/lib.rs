@@ -1,10 +1,22 @@
 mod args;
+mod output;
+mod report;
+mod sarif;
 
 use crate::args::Args;
-use clap::Parser;
+use crate::output::JsonOutput;
+use crate::report::{
+    get_exit_code, get_workspaces_exit_code, print_result, print_workspaces_result, FailOn,
+};
+use crate::sarif::SarifLog;
+use clap::{Parser, ValueEnum};
 use depckeck_rs_core::checker::Checker;
 use depckeck_rs_core::config::Config;
+use eyre::WrapErr;
 use proc_exit::WithCodeResultExt;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
 
 pub fn run() {
     human_panic::setup_panic!();
@@ -32,6 +44,13 @@ fn try_run() -> proc_exit::ExitResult {
         ignore_path,
         ignore_patterns,
         ignore_matches,
+        flow,
+        node_version,
+        workspaces,
+        json,
+        sarif,
+        output,
+        fail_on,
         verbose,
     } = args;
 
@@ -39,10 +58,12 @@ fn try_run() -> proc_exit::ExitResult {
         .filter_level(verbose.log_level_filter())
         .init();
 
-    let mut config = Config::new(directory)
+    let mut config = Config::new(directory.clone())
         .with_ignore_bin_package(ignore_bin_package)
         .with_skip_missing(skip_missing)
-        .with_ignore_path(ignore_path);
+        .with_ignore_path(ignore_path)
+        .with_flow(flow)
+        .with_node_version(node_version);
 
     if let Some(ignore_patterns) = ignore_patterns {
         config = config.with_ignore_patterns(ignore_patterns);
@@ -52,11 +73,63 @@ fn try_run() -> proc_exit::ExitResult {
         config = config.with_ignore_matches(ignore_matches);
     }
 
-    let result = Checker::new(config)
-        .check_package()
-        .with_code(proc_exit::Code::USAGE_ERR)?;
+    let checker = Checker::new(config);
 
-    println!("{:#?}", result);
+    let is_json = json || (output.is_some() && !sarif);
+    let fail_on = fail_on.unwrap_or_else(|| FailOn::value_variants().to_vec());
 
-    Ok(())
+    let exit_code = if workspaces {
+        let result = checker
+            .check_workspaces()
+            .with_code(proc_exit::Code::USAGE_ERR)?;
+        let exit_code = get_workspaces_exit_code(&result, &fail_on);
+
+        if sarif {
+            let sarif_log = SarifLog::from_workspaces_result(&directory, &result);
+            write_output(&sarif_log, output.as_deref())?;
+        } else if is_json {
+            let json_output = JsonOutput::from_workspaces_result(&directory, result);
+            write_output(&json_output, output.as_deref())?;
+        } else {
+            print_workspaces_result(&result).with_code(proc_exit::Code::IO_ERR)?;
+        }
+
+        exit_code
+    } else {
+        let result = checker
+            .check_package()
+            .with_code(proc_exit::Code::USAGE_ERR)?;
+        let exit_code = get_exit_code(&result, &fail_on);
+
+        if sarif {
+            let sarif_log = SarifLog::from_result(&directory, &result);
+            write_output(&sarif_log, output.as_deref())?;
+        } else if is_json {
+            let json_output = JsonOutput::from_result(&directory, result);
+            write_output(&json_output, output.as_deref())?;
+        } else {
+            print_result(&result).with_code(proc_exit::Code::IO_ERR)?;
+        }
+
+        exit_code
+    };
+
+    proc_exit::Code::new(exit_code).ok()
+}
+
+/// Print the JSON or SARIF output or write it to the output file.
+fn write_output(json_output: &impl Serialize, output: Option<&Path>) -> proc_exit::ExitResult {
+    let json = serde_json::to_string_pretty(json_output)
+        .wrap_err("Failed to serialize the result")
+        .with_code(proc_exit::Code::SOFTWARE_ERR)?;
+
+    match output {
+        Some(output) => fs::write(output, json + "\n")
+            .wrap_err_with(|| format!("Failed to write the result to {:?}", output))
+            .with_code(proc_exit::Code::IO_ERR),
+        None => {
+            println!("{}", json);
+            Ok(())
+        }
+    }
 }
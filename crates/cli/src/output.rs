@@ -0,0 +1,155 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+
+use depckeck_rs_core::checker_result::{CheckerResult, ImportLocation, WorkspacesCheckerResult};
+
+/// Version of the JSON output schema, increased on breaking changes.
+const SCHEMA_VERSION: u32 = 1;
+
+/// The JSON output of the CLI.
+///
+/// Maps and sets are sorted, so the output of the same project is deterministic.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonOutput {
+    /// Version of the output schema.
+    pub schema_version: u32,
+    pub tool: Tool,
+    /// The checked directory.
+    pub directory: String,
+    /// Result of the package, without `--workspaces`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<JsonResult>,
+    /// Results by workspace directory, `.` for the root package, with `--workspaces`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspaces: Option<BTreeMap<String, JsonResult>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResult {
+    pub using_dependencies: BTreeMap<String, BTreeSet<String>>,
+    pub import_locations: BTreeMap<String, BTreeSet<ImportLocation>>,
+    pub missing_dependencies: BTreeMap<String, BTreeSet<String>>,
+    pub phantom_dependencies: BTreeMap<String, BTreeSet<String>>,
+    pub unused_dependencies: BTreeSet<String>,
+    pub unused_dev_dependencies: BTreeSet<String>,
+}
+
+impl JsonOutput {
+    fn new(directory: &Path) -> Self {
+        let directory = directory
+            .canonicalize()
+            .unwrap_or_else(|_| directory.to_path_buf());
+
+        JsonOutput {
+            schema_version: SCHEMA_VERSION,
+            tool: Tool {
+                name: String::from("depcheck-rs"),
+                version: String::from(env!("CARGO_PKG_VERSION")),
+            },
+            directory: directory.to_string_lossy().into_owned(),
+            result: None,
+            workspaces: None,
+        }
+    }
+
+    pub fn from_result(directory: &Path, result: CheckerResult) -> Self {
+        JsonOutput {
+            result: Some(result.into()),
+            ..JsonOutput::new(directory)
+        }
+    }
+
+    pub fn from_workspaces_result(directory: &Path, result: WorkspacesCheckerResult) -> Self {
+        let workspaces = result
+            .workspaces
+            .into_iter()
+            .map(|(workspace, result)| (workspace, result.into()))
+            .collect();
+
+        JsonOutput {
+            workspaces: Some(workspaces),
+            ..JsonOutput::new(directory)
+        }
+    }
+}
+
+impl From<CheckerResult> for JsonResult {
+    fn from(result: CheckerResult) -> Self {
+        let CheckerResult {
+            using_dependencies,
+            import_locations,
+            missing_dependencies,
+            phantom_dependencies,
+            unused_dependencies,
+            unused_dev_dependencies,
+        } = result;
+
+        JsonResult {
+            using_dependencies: sort_files(using_dependencies),
+            import_locations,
+            missing_dependencies: sort_files(missing_dependencies),
+            phantom_dependencies: sort_files(phantom_dependencies),
+            unused_dependencies: unused_dependencies.into_iter().collect(),
+            unused_dev_dependencies: unused_dev_dependencies.into_iter().collect(),
+        }
+    }
+}
+
+fn sort_files(
+    dependencies: BTreeMap<String, HashSet<String>>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    dependencies
+        .into_iter()
+        .map(|(dependency, files)| (dependency, files.into_iter().collect()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorts_result() {
+        let result = CheckerResult {
+            using_dependencies: BTreeMap::from([(
+                String::from("react"),
+                HashSet::from([String::from("b.js"), String::from("a.js")]),
+            )]),
+            unused_dependencies: HashSet::from([String::from("lodash"), String::from("chalk")]),
+            ..Default::default()
+        };
+
+        let json_output = JsonOutput::from_result(Path::new("missing-directory"), result);
+        let actual = serde_json::to_value(&json_output).unwrap();
+
+        let expected = serde_json::json!({
+            "schemaVersion": 1,
+            "tool": {
+                "name": "depcheck-rs",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "directory": "missing-directory",
+            "result": {
+                "usingDependencies": {
+                    "react": ["a.js", "b.js"],
+                },
+                "importLocations": {},
+                "missingDependencies": {},
+                "phantomDependencies": {},
+                "unusedDependencies": ["chalk", "lodash"],
+                "unusedDevDependencies": [],
+            },
+        });
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io;
+
+use depckeck_rs_core::checker_result::{CheckerResult, WorkspacesCheckerResult};
+use is_terminal::IsTerminal;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+/// Categories of issues the CLI exits with a failure code for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum FailOn {
+    /// No category, to always exit successfully.
+    None,
+    /// Unused dependencies.
+    Unused,
+    /// Unused dev dependencies.
+    UnusedDev,
+    /// Missing dependencies.
+    Missing,
+    /// Phantom dependencies.
+    Phantom,
+}
+
+impl FailOn {
+    /// Get the bit of the category in the exit code.
+    fn get_exit_bit(self) -> i32 {
+        match self {
+            FailOn::None => 0,
+            FailOn::Unused => 1,
+            FailOn::UnusedDev => 2,
+            FailOn::Missing => 4,
+            FailOn::Phantom => 8,
+        }
+    }
+}
+
+/// Print a result the way the original depcheck does, with color when stdout is a terminal.
+pub fn print_result(result: &CheckerResult) -> io::Result<()> {
+    let mut stdout = StandardStream::stdout(get_color_choice());
+    write_result(&mut stdout, result)
+}
+
+/// Print results of every workspace, with color when stdout is a terminal.
+pub fn print_workspaces_result(result: &WorkspacesCheckerResult) -> io::Result<()> {
+    let mut stdout = StandardStream::stdout(get_color_choice());
+    write_workspaces_result(&mut stdout, result)
+}
+
+/// Get the exit code of a result, a bitmask of the categories it has issues of:
+/// `1` for unused, `2` for unused dev, `4` for missing and `8` for phantom dependencies.
+pub fn get_exit_code(result: &CheckerResult, fail_on: &[FailOn]) -> i32 {
+    fail_on
+        .iter()
+        .filter(|category| match category {
+            FailOn::None => false,
+            FailOn::Unused => !result.unused_dependencies.is_empty(),
+            FailOn::UnusedDev => !result.unused_dev_dependencies.is_empty(),
+            FailOn::Missing => !result.missing_dependencies.is_empty(),
+            FailOn::Phantom => !result.phantom_dependencies.is_empty(),
+        })
+        .fold(0, |code, category| code | category.get_exit_bit())
+}
+
+/// Get the exit code of results of every workspace, a bitmask of the categories
+/// any workspace has issues of.
+pub fn get_workspaces_exit_code(result: &WorkspacesCheckerResult, fail_on: &[FailOn]) -> i32 {
+    result
+        .workspaces
+        .values()
+        .fold(0, |code, result| code | get_exit_code(result, fail_on))
+}
+
+fn get_color_choice() -> ColorChoice {
+    if io::stdout().is_terminal() {
+        ColorChoice::Auto
+    } else {
+        ColorChoice::Never
+    }
+}
+
+fn write_workspaces_result(
+    writer: &mut impl WriteColor,
+    result: &WorkspacesCheckerResult,
+) -> io::Result<()> {
+    for (workspace, result) in &result.workspaces {
+        write_heading(writer, &format!("Workspace {workspace}"), Color::Cyan)?;
+        write_result(writer, result)?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+fn write_result(writer: &mut impl WriteColor, result: &CheckerResult) -> io::Result<()> {
+    let has_issues = !result.unused_dependencies.is_empty()
+        || !result.unused_dev_dependencies.is_empty()
+        || !result.missing_dependencies.is_empty()
+        || !result.phantom_dependencies.is_empty();
+
+    if !has_issues {
+        writer.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+        writeln!(writer, "No depcheck issue")?;
+        return writer.reset();
+    }
+
+    write_dependencies(writer, "Unused dependencies", &result.unused_dependencies)?;
+    write_dependencies(
+        writer,
+        "Unused devDependencies",
+        &result.unused_dev_dependencies,
+    )?;
+    write_dependency_files(writer, "Missing dependencies", &result.missing_dependencies)?;
+    write_dependency_files(
+        writer,
+        "Phantom dependencies, installed only by other dependencies",
+        &result.phantom_dependencies,
+    )
+}
+
+fn write_heading(writer: &mut impl WriteColor, heading: &str, color: Color) -> io::Result<()> {
+    writer.set_color(ColorSpec::new().set_fg(Some(color)).set_bold(true))?;
+    writeln!(writer, "{heading}")?;
+    writer.reset()
+}
+
+/// Write a section listing dependencies, e.g. `* lodash`.
+fn write_dependencies(
+    writer: &mut impl WriteColor,
+    heading: &str,
+    dependencies: &HashSet<String>,
+) -> io::Result<()> {
+    if dependencies.is_empty() {
+        return Ok(());
+    }
+
+    write_heading(writer, heading, Color::Yellow)?;
+
+    for dependency in dependencies.iter().collect::<BTreeSet<_>>() {
+        writeln!(writer, "* {dependency}")?;
+    }
+
+    Ok(())
+}
+
+/// Write a section listing dependencies with the files using them,
+/// e.g. `* react: src/App.jsx, src/index.jsx`.
+fn write_dependency_files(
+    writer: &mut impl WriteColor,
+    heading: &str,
+    dependencies: &BTreeMap<String, HashSet<String>>,
+) -> io::Result<()> {
+    if dependencies.is_empty() {
+        return Ok(());
+    }
+
+    write_heading(writer, heading, Color::Red)?;
+
+    for (dependency, files) in dependencies {
+        let mut files: Vec<_> = files.iter().map(String::as_str).collect();
+        files.sort_unstable();
+
+        write!(writer, "* {dependency}: ")?;
+        writer.set_color(ColorSpec::new().set_fg(Some(Color::Blue)))?;
+        write!(writer, "{}", files.join(", "))?;
+        writer.reset()?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use termcolor::Buffer;
+
+    #[test]
+    fn writes_result() {
+        let result = CheckerResult {
+            missing_dependencies: BTreeMap::from([(
+                String::from("react"),
+                HashSet::from([String::from("src/index.jsx"), String::from("src/App.jsx")]),
+            )]),
+            phantom_dependencies: BTreeMap::from([(
+                String::from("scheduler"),
+                HashSet::from([String::from("src/App.jsx")]),
+            )]),
+            unused_dependencies: HashSet::from([String::from("lodash"), String::from("chalk")]),
+            unused_dev_dependencies: HashSet::from([String::from("jest")]),
+            ..Default::default()
+        };
+
+        let mut buffer = Buffer::no_color();
+        write_result(&mut buffer, &result).unwrap();
+
+        let expected = "\
+Unused dependencies
+* chalk
+* lodash
+Unused devDependencies
+* jest
+Missing dependencies
+* react: src/App.jsx, src/index.jsx
+Phantom dependencies, installed only by other dependencies
+* scheduler: src/App.jsx
+";
+
+        assert_eq!(String::from_utf8(buffer.into_inner()).unwrap(), expected);
+        assert_eq!(get_exit_code(&result, &[FailOn::Missing]), 4);
+        assert_eq!(get_exit_code(&result, &[FailOn::Phantom]), 8);
+        assert_eq!(
+            get_exit_code(
+                &result,
+                &[FailOn::Unused, FailOn::UnusedDev, FailOn::Missing]
+            ),
+            7
+        );
+        assert_eq!(get_exit_code(&result, &[FailOn::None]), 0);
+    }
+
+    #[test]
+    fn writes_no_issue() {
+        let mut buffer = Buffer::no_color();
+        write_result(&mut buffer, &CheckerResult::default()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer.into_inner()).unwrap(),
+            "No depcheck issue\n"
+        );
+    }
+}
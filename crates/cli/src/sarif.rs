@@ -0,0 +1,556 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs;
+use std::path::Path;
+
+use depckeck_rs_core::checker_result::{CheckerResult, WorkspacesCheckerResult};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const VERSION: &str = "2.1.0";
+/// Base of artifact locations, the checked directory.
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+/// Rule ids and descriptions, one per category of issues.
+const UNUSED_DEPENDENCY: (&str, &str) = (
+    "unused-dependency",
+    "Dependency is declared in package.json but not used",
+);
+const UNUSED_DEV_DEPENDENCY: (&str, &str) = (
+    "unused-dev-dependency",
+    "Dev dependency is declared in package.json but not used",
+);
+const MISSING_DEPENDENCY: (&str, &str) = (
+    "missing-dependency",
+    "Dependency is used but not declared in package.json",
+);
+const PHANTOM_DEPENDENCY: (&str, &str) = (
+    "phantom-dependency",
+    "Dependency is used but not declared in package.json, \
+     it is only installed because other dependencies depend on it",
+);
+
+/// The SARIF 2.1.0 output of the CLI, for code scanning.
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+    pub tool: Tool,
+    /// Columns of regions count Unicode code points, not UTF-16 code units.
+    pub column_kind: &'static str,
+    pub original_uri_base_ids: BTreeMap<&'static str, ArtifactLocation>,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Tool {
+    pub driver: Driver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub information_uri: &'static str,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+    pub id: &'static str,
+    pub short_description: Message,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: &'static str,
+    pub level: &'static str,
+    pub message: Message,
+    pub locations: Vec<Location>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub physical_location: PhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<&'static str>,
+}
+
+/// A region of an artifact, lines and columns start from 1.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    pub start_column: usize,
+}
+
+impl SarifLog {
+    fn new(directory: &Path, results: Vec<SarifResult>) -> Self {
+        let directory = directory
+            .canonicalize()
+            .unwrap_or_else(|_| directory.to_path_buf());
+
+        let rules = [
+            UNUSED_DEPENDENCY,
+            UNUSED_DEV_DEPENDENCY,
+            MISSING_DEPENDENCY,
+            PHANTOM_DEPENDENCY,
+        ]
+        .into_iter()
+        .map(|(id, description)| Rule {
+            id,
+            short_description: Message {
+                text: String::from(description),
+            },
+        })
+        .collect();
+
+        let original_uri_base_ids = BTreeMap::from([(
+            SOURCE_ROOT,
+            ArtifactLocation {
+                uri: get_directory_uri(&directory),
+                uri_base_id: None,
+            },
+        )]);
+
+        SarifLog {
+            schema: SCHEMA,
+            version: VERSION,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "depcheck-rs",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: "https://github.com/denbezrukov/depcheck-rs",
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                original_uri_base_ids,
+                results,
+            }],
+        }
+    }
+
+    pub fn from_result(directory: &Path, result: &CheckerResult) -> Self {
+        let results = get_results(directory, ".", result);
+
+        SarifLog::new(directory, results)
+    }
+
+    pub fn from_workspaces_result(directory: &Path, result: &WorkspacesCheckerResult) -> Self {
+        let results = result
+            .workspaces
+            .iter()
+            .flat_map(|(workspace, result)| {
+                get_results(&directory.join(workspace), workspace, result)
+            })
+            .collect();
+
+        SarifLog::new(directory, results)
+    }
+}
+
+/// Get results of a package, with locations relative to the checked directory.
+fn get_results(directory: &Path, workspace: &str, result: &CheckerResult) -> Vec<SarifResult> {
+    let package_json_uri = get_uri(workspace, "package.json");
+    let package_json = fs::read_to_string(directory.join("package.json")).unwrap_or_default();
+
+    let unused_results = [
+        (
+            UNUSED_DEPENDENCY.0,
+            "dependencies",
+            &result.unused_dependencies,
+        ),
+        (
+            UNUSED_DEV_DEPENDENCY.0,
+            "devDependencies",
+            &result.unused_dev_dependencies,
+        ),
+    ]
+    .into_iter()
+    .flat_map(|(rule_id, field, dependencies)| {
+        sort_dependencies(dependencies).map(move |dependency| (rule_id, field, dependency))
+    })
+    .map(|(rule_id, field, dependency)| SarifResult {
+        rule_id,
+        level: "warning",
+        message: Message {
+            text: format!("{dependency} is declared in {field} but not used"),
+        },
+        locations: vec![get_location(
+            package_json_uri.clone(),
+            find_dependency_region(&package_json, field, dependency),
+        )],
+    });
+
+    let missing_dependencies = result
+        .missing_dependencies
+        .iter()
+        .map(|(dependency, files)| (MISSING_DEPENDENCY.0, dependency, files));
+    let phantom_dependencies = result
+        .phantom_dependencies
+        .iter()
+        .map(|(dependency, files)| (PHANTOM_DEPENDENCY.0, dependency, files));
+
+    let missing_results = missing_dependencies
+        .chain(phantom_dependencies)
+        .flat_map(|(rule_id, dependency, files)| {
+            sort_dependencies(files).map(move |file| (rule_id, dependency, file))
+        })
+        .flat_map(|(rule_id, dependency, file)| {
+            get_import_regions(result, dependency, file)
+                .into_iter()
+                .map(move |region| (rule_id, dependency, file, region))
+        })
+        .map(|(rule_id, dependency, file, region)| SarifResult {
+            rule_id,
+            level: "error",
+            message: Message {
+                text: format!("{dependency} is used but not declared in {package_json_uri}"),
+            },
+            locations: vec![get_location(get_uri(workspace, file), region)],
+        });
+
+    unused_results.chain(missing_results).collect()
+}
+
+/// Get regions of every import of a dependency in a file, or a single empty region
+/// when the file uses the dependency without importing it, like configs do.
+fn get_import_regions(result: &CheckerResult, dependency: &str, file: &str) -> Vec<Option<Region>> {
+    let regions: Vec<_> = result
+        .import_locations
+        .get(dependency)
+        .into_iter()
+        .flatten()
+        .filter(|location| location.file == file)
+        .map(|location| {
+            Some(Region {
+                start_line: location.line,
+                start_column: location.column,
+            })
+        })
+        .collect();
+
+    if regions.is_empty() {
+        vec![None]
+    } else {
+        regions
+    }
+}
+
+fn sort_dependencies(dependencies: &HashSet<String>) -> impl Iterator<Item = &String> {
+    dependencies.iter().collect::<BTreeSet<_>>().into_iter()
+}
+
+fn get_location(uri: String, region: Option<Region>) -> Location {
+    Location {
+        physical_location: PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri,
+                uri_base_id: Some(SOURCE_ROOT),
+            },
+            region,
+        },
+    }
+}
+
+/// Get the uri of a file relative to the checked directory.
+fn get_uri(workspace: &str, file: &str) -> String {
+    if workspace == "." {
+        encode_path(file)
+    } else {
+        encode_path(&format!("{}/{file}", workspace.trim_end_matches('/')))
+    }
+}
+
+/// Get the `file://` URI of a directory, with a trailing slash.
+fn get_directory_uri(directory: &Path) -> String {
+    let path = directory.to_string_lossy();
+
+    // Canonical paths on Windows are verbatim, e.g. `\\?\C:\project` or `\\?\UNC\server\share`.
+    if let Some(share) = path.strip_prefix(r"\\?\UNC\") {
+        return format!("file://{}/", encode_path(share).trim_end_matches('/'));
+    }
+
+    let path = path.strip_prefix(r"\\?\").unwrap_or(&path);
+    let path = encode_path(path);
+    let path = path.trim_end_matches('/');
+
+    if path.starts_with('/') {
+        format!("file://{path}/")
+    } else {
+        format!("file:///{path}/")
+    }
+}
+
+/// Percent-encode every segment of a path, with `/` as the separator.
+fn encode_path(path: &str) -> String {
+    path.split(|character| character == '/' || character == '\\')
+        .map(encode_path_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Percent-encode the bytes of a path segment which aren't allowed in URIs, like spaces,
+/// `#`, `%` or non-ASCII characters.
+fn encode_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@' => char::from(byte).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Find the region of a dependency key in a field of package.json, e.g. `"lodash"` of
+/// `"dependencies": { "lodash": "^4.17.21" }`.
+fn find_dependency_region(package_json: &str, field: &str, dependency: &str) -> Option<Region> {
+    let root_start = package_json.find('{')?;
+    let field_offset = find_object_key(package_json, root_start, field)?;
+
+    let value = package_json[field_offset..]
+        .split_once(':')
+        .map(|(_, value)| value.trim_start())?;
+    if !value.starts_with('{') {
+        return None;
+    }
+
+    let object_start = package_json.len() - value.len();
+    let offset = find_object_key(package_json, object_start, dependency)?;
+
+    let line_start = package_json[..offset]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+
+    Some(Region {
+        start_line: package_json[..offset].matches('\n').count() + 1,
+        start_column: package_json[line_start..offset].chars().count() + 1,
+    })
+}
+
+/// Find the offset of a key of the JSON object starting at an offset,
+/// without looking into nested values or past the end of the object.
+fn find_object_key(json: &str, object_start: usize, key: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut characters = json[object_start..]
+        .char_indices()
+        .map(|(index, character)| (object_start + index, character));
+
+    while let Some((offset, character)) = characters.next() {
+        match character {
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return None;
+                }
+            }
+            '"' => {
+                let mut end = None;
+                while let Some((index, character)) = characters.next() {
+                    match character {
+                        '\\' => {
+                            characters.next();
+                        }
+                        '"' => {
+                            end = Some(index);
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                let end = end?;
+
+                if depth == 1
+                    && json[offset + 1..end] == *key
+                    && json[end + 1..].trim_start().starts_with(':')
+                {
+                    return Some(offset);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use depckeck_rs_core::checker_result::{ImportKind, ImportLocation};
+
+    #[test]
+    fn finds_dependency_region() {
+        let package_json = r#"{
+  "name": "lodash",
+  "dependencies": {
+    "react": "^18.0.0",
+    "lodash": "^4.17.21"
+  },
+  "devDependencies": {
+    "lodash": "^4.17.21",
+    "eslint-config": {
+      "react": "^18.0.0"
+    }
+  },
+  "overrides": {
+    "react": "^18.0.0"
+  }
+}"#;
+
+        assert_eq!(
+            find_dependency_region(package_json, "dependencies", "lodash"),
+            Some(Region {
+                start_line: 5,
+                start_column: 5
+            })
+        );
+        assert_eq!(
+            find_dependency_region(package_json, "devDependencies", "lodash"),
+            Some(Region {
+                start_line: 8,
+                start_column: 5
+            })
+        );
+        assert_eq!(
+            find_dependency_region(package_json, "devDependencies", "react"),
+            None
+        );
+    }
+
+    #[test]
+    fn encodes_uris() {
+        assert_eq!(
+            get_directory_uri(Path::new("/home/user/my project#1/")),
+            "file:///home/user/my%20project%231/"
+        );
+        assert_eq!(
+            get_directory_uri(Path::new(r"\\?\C:\Users\me\my project")),
+            "file:///C:/Users/me/my%20project/"
+        );
+        assert_eq!(
+            get_directory_uri(Path::new(r"\\?\UNC\server\share\100%")),
+            "file://server/share/100%25/"
+        );
+        assert_eq!(
+            get_uri("packages/my app", "src/caf\u{e9}.js"),
+            "packages/my%20app/src/caf%C3%A9.js"
+        );
+        assert_eq!(get_uri(".", "src/index.js"), "src/index.js");
+    }
+
+    #[test]
+    fn reports_result() {
+        let result = CheckerResult {
+            missing_dependencies: BTreeMap::from([(
+                String::from("react"),
+                HashSet::from([String::from("src/index.jsx")]),
+            )]),
+            phantom_dependencies: BTreeMap::from([(
+                String::from("scheduler"),
+                HashSet::from([String::from("src/App.jsx")]),
+            )]),
+            import_locations: BTreeMap::from([(
+                String::from("react"),
+                BTreeSet::from([ImportLocation {
+                    file: String::from("src/index.jsx"),
+                    line: 1,
+                    column: 1,
+                    kind: ImportKind::Import,
+                }]),
+            )]),
+            unused_dependencies: HashSet::from([String::from("lodash")]),
+            ..Default::default()
+        };
+
+        let sarif_log = SarifLog::from_result(Path::new("missing-directory"), &result);
+        let actual = serde_json::to_value(&sarif_log.runs[0].results).unwrap();
+
+        let expected = serde_json::json!([
+            {
+                "ruleId": "unused-dependency",
+                "level": "warning",
+                "message": { "text": "lodash is declared in dependencies but not used" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "package.json", "uriBaseId": "%SRCROOT%" },
+                    },
+                }],
+            },
+            {
+                "ruleId": "missing-dependency",
+                "level": "error",
+                "message": { "text": "react is used but not declared in package.json" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "src/index.jsx", "uriBaseId": "%SRCROOT%" },
+                        "region": { "startLine": 1, "startColumn": 1 },
+                    },
+                }],
+            },
+            {
+                "ruleId": "phantom-dependency",
+                "level": "error",
+                "message": { "text": "scheduler is used but not declared in package.json" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "src/App.jsx", "uriBaseId": "%SRCROOT%" },
+                    },
+                }],
+            },
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,19 +1,19 @@
-use eyre::WrapErr;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
-use ignore::overrides::OverrideBuilder;
-use ignore::{self, WalkBuilder};
 use relative_path::RelativePathBuf;
 use swc_common::comments::SingleThreadedComments;
 use swc_ecma_dep_graph::analyze_dependencies;
 
-use crate::checker_result::CheckerResult;
+use crate::checker_result::{CheckerResult, ImportLocation, WorkspacesCheckerResult};
 use crate::config::Config;
-use crate::dependency::Dependency;
-use crate::package::Package;
+use crate::dependency::{expand_dependencies, Dependency};
+use crate::lockfile::Lockfile;
 use crate::parser::Parser;
+use crate::special::check_specials;
+use crate::stylesheet::parse_stylesheet;
+use crate::util::build_walker::build_walker;
 use crate::util::is_module::is_module;
-use crate::util::load_module::load_module;
+use crate::workspace::{find_workspace, find_workspace_members, Workspace};
 use std::path::PathBuf;
 use std::sync::mpsc;
 use std::sync::mpsc::{Receiver, Sender};
@@ -31,13 +31,21 @@ impl Checker {
     pub fn new(config: Config) -> Self {
         log::trace!("init checker with config {:#?}", config);
 
+        let parser = Parser::default().with_flow(config.flow());
+
         Checker {
             config: Arc::new(config),
-            parser: Default::default(),
+            parser: Arc::new(parser),
         }
     }
 }
 
+/// Using dependencies of a workspace and import locations of the dependencies.
+type WorkspaceDependencies = (
+    BTreeMap<String, HashSet<String>>,
+    BTreeMap<String, BTreeSet<ImportLocation>>,
+);
+
 pub enum WorkerResult {
     Entry(PathBuf),
     Error(ignore::Error),
@@ -50,78 +58,153 @@ impl Checker {
 
         log::debug!("checking directory {:#?}", directory);
 
-        let package = load_module(directory)
-            .wrap_err_with(|| format!("Failed to read package json from {:?}", directory))?;
-
-        log::debug!("loaded package json {:#?}", package);
+        let workspace = Workspace::new(&self.config, RelativePathBuf::new())?;
 
-        let using_dependencies = self.check_directory(Arc::new(package.clone()))?;
-
-        let result = CheckerResult::new(using_dependencies, package, &self.config);
+        let result = self
+            .check_members(vec![workspace])?
+            .into_iter()
+            .next()
+            .map(|(_, result)| result)
+            .unwrap_or_default();
 
         Ok(result)
     }
 
-    fn check_directory(
-        &self,
-        package: Arc<Package>,
-    ) -> eyre::Result<BTreeMap<String, HashSet<String>>> {
+    /// check dependencies of the package and of every member of its npm, yarn or pnpm workspaces
+    /// with a single walk of the directory.
+    pub fn check_workspaces(self) -> eyre::Result<WorkspacesCheckerResult> {
         let directory = self.config.get_directory();
-        let mut override_builder = OverrideBuilder::new(directory);
 
-        for pattern in self.config.get_ignore_patterns() {
-            override_builder
-                .add(&format!("!{pattern}"))
-                .wrap_err_with(|| format!("Malformed ignore pattern: {pattern}"))?;
-        }
+        log::debug!("checking workspaces of directory {:#?}", directory);
 
-        let overrides = override_builder
-            .build()
-            .wrap_err_with(|| "Failed to build override builder")?;
-        let mut walker = WalkBuilder::new(directory);
+        let root = Workspace::new(&self.config, RelativePathBuf::new())?;
 
-        walker.overrides(overrides);
+        let mut workspaces = vec![root];
+        for path in find_workspace_members(&self.config, &workspaces[0].package)? {
+            log::debug!("found workspace {:#?}", path);
 
-        if let Some(path) = self.config.ignore_path() {
-            walker.add_custom_ignore_filename(path);
+            let mut workspace = Workspace::new(&self.config, path)?;
+            // Members are installed by the lockfile of the root package.
+            if workspace.lockfile == Lockfile::default() {
+                workspace.lockfile = workspaces[0].lockfile.clone();
+            }
+
+            workspaces.push(workspace);
         }
 
+        let results = self.check_members(workspaces)?;
+
+        Ok(WorkspacesCheckerResult::new(results))
+    }
+
+    fn check_members(
+        &self,
+        workspaces: Vec<Workspace>,
+    ) -> eyre::Result<Vec<(Workspace, CheckerResult)>> {
+        let workspaces = Arc::new(workspaces);
+        let dependencies = self.check_directory(&workspaces)?;
+
+        let workspaces =
+            Arc::try_unwrap(workspaces).unwrap_or_else(|workspaces| (*workspaces).clone());
+
+        let results = workspaces
+            .into_iter()
+            .zip(dependencies)
+            .map(|(workspace, (using_dependencies, import_locations))| {
+                let result = CheckerResult::new(
+                    using_dependencies,
+                    import_locations,
+                    workspace.package.clone(),
+                    &workspace.config,
+                    &workspace.lockfile,
+                    &workspace.resolver,
+                );
+                (workspace, result)
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Get using dependencies and their import locations of each workspace.
+    fn check_directory(
+        &self,
+        workspaces: &Arc<Vec<Workspace>>,
+    ) -> eyre::Result<Vec<WorkspaceDependencies>> {
+        let mut walker = build_walker(&self.config, self.config.get_directory())?;
+
         let (file_sender, file_receiver) = mpsc::channel();
         let (dependency_sender, dependency_receiver) = mpsc::channel();
 
         let nums_of_thread = num_cpus::get();
         let parallel_walker = walker.threads(nums_of_thread).build_parallel();
 
-        let mut using_dependencies = BTreeMap::new();
+        let mut using_dependencies = vec![BTreeMap::new(); workspaces.len()];
+        let mut import_locations = vec![BTreeMap::new(); workspaces.len()];
 
-        let handle = spawn_dependency_senders(
-            file_receiver,
-            dependency_sender,
-            &self.config,
-            &self.parser,
-            &package,
-        );
+        let handle =
+            spawn_dependency_senders(file_receiver, dependency_sender, &self.parser, workspaces);
 
-        spawn_file_senders(parallel_walker, file_sender);
+        let workspace_directories = workspaces
+            .iter()
+            .map(|workspace| workspace.get_directory().to_path_buf())
+            .collect();
+
+        spawn_file_senders(parallel_walker, file_sender, workspace_directories);
 
         handle.join().unwrap();
 
-        while let Ok((file, file_dependencies)) = dependency_receiver.recv() {
-            for dependency in file_dependencies {
-                let files = using_dependencies
+        while let Ok((index, file, file_dependencies)) = dependency_receiver.recv() {
+            for (dependency, import_location) in file_dependencies {
+                if let Some(import_location) = import_location {
+                    import_locations[index]
+                        .entry(dependency.clone())
+                        .or_insert_with(BTreeSet::new)
+                        .insert(import_location);
+                }
+
+                let files = using_dependencies[index]
                     .entry(dependency)
                     .or_insert_with(|| HashSet::with_capacity(100));
                 files.insert(file.to_string());
             }
         }
 
-        Ok(using_dependencies)
+        for (workspace, using_dependencies) in workspaces.iter().zip(&mut using_dependencies) {
+            let specials = check_specials(
+                workspace.get_directory(),
+                &workspace.package,
+                &workspace.resolver,
+            );
+
+            for (file, file_dependencies) in specials {
+                let file_dependencies =
+                    expand_dependencies(file_dependencies.into_iter().collect(), workspace);
+
+                for dependency in file_dependencies {
+                    let files = using_dependencies
+                        .entry(dependency)
+                        .or_insert_with(|| HashSet::with_capacity(100));
+                    files.insert(file.to_string());
+                }
+            }
+        }
+
+        Ok(using_dependencies
+            .into_iter()
+            .zip(import_locations)
+            .collect())
     }
 }
 
-fn spawn_file_senders(parallel_walker: ignore::WalkParallel, file_sender: Sender<WorkerResult>) {
+fn spawn_file_senders(
+    parallel_walker: ignore::WalkParallel,
+    file_sender: Sender<WorkerResult>,
+    workspace_directories: HashSet<PathBuf>,
+) {
     parallel_walker.run(|| {
         let file_sender = file_sender.clone();
+        let workspace_directories = &workspace_directories;
         Box::new(move |entry| {
             log::debug!("walk entry {:#?}", entry);
             return match entry {
@@ -130,7 +213,8 @@ fn spawn_file_senders(parallel_walker: ignore::WalkParallel, file_sender: Sender
                         return ignore::WalkState::Continue;
                     }
 
-                    if is_module(entry.path()) {
+                    // Nested packages are checked on their own, unless they are workspaces.
+                    if is_module(entry.path()) && !workspace_directories.contains(entry.path()) {
                         return ignore::WalkState::Skip;
                     }
 
@@ -161,14 +245,16 @@ fn spawn_file_senders(parallel_walker: ignore::WalkParallel, file_sender: Sender
 
 fn spawn_dependency_senders(
     file_receiver: Receiver<WorkerResult>,
-    dependency_sender: Sender<(RelativePathBuf, HashSet<String>)>,
-    config: &Arc<Config>,
+    dependency_sender: Sender<(
+        usize,
+        RelativePathBuf,
+        HashSet<(String, Option<ImportLocation>)>,
+    )>,
     parser: &Arc<Parser>,
-    package: &Arc<Package>,
+    workspaces: &Arc<Vec<Workspace>>,
 ) -> thread::JoinHandle<Vec<()>> {
-    let config = Arc::clone(config);
     let parser = Arc::clone(parser);
-    let package = Arc::clone(package);
+    let workspaces = Arc::clone(workspaces);
     thread::spawn(move || {
         let shared_file_receiver = Arc::new(Mutex::new(file_receiver));
 
@@ -177,9 +263,8 @@ fn spawn_dependency_senders(
 
         for _ in 0..nums_of_thread {
             let file_receiver = Arc::clone(&shared_file_receiver);
-            let config = Arc::clone(&config);
             let parser = Arc::clone(&parser);
-            let package = Arc::clone(&package);
+            let workspaces = Arc::clone(&workspaces);
             let dependency_sender = dependency_sender.clone();
 
             let handle = thread::spawn(move || loop {
@@ -194,6 +279,15 @@ fn spawn_dependency_senders(
                 };
 
                 drop(lock);
+
+                let (index, workspace) = match find_workspace(&workspaces, &path) {
+                    Some(workspace) => workspace,
+                    None => continue,
+                };
+                let Workspace {
+                    config, tsconfig, ..
+                } = workspace;
+
                 let comments = SingleThreadedComments::default();
 
                 let file = path
@@ -201,19 +295,41 @@ fn spawn_dependency_senders(
                     .map(|path| RelativePathBuf::from_path(path).ok())
                     .ok()
                     .flatten();
-                let file_dependencies = parser.parse_file(&path).map(|(module, syntax)| {
-                    analyze_dependencies(&module, &comments)
-                        .into_iter()
-                        .map(Dependency::new)
-                        .filter(|dependency| dependency.is_external())
-                        .flat_map(|dependency| {
-                            dependency.extract_dependencies(&syntax, &package, &config)
-                        })
-                        .collect::<HashSet<_>>()
-                });
+                let file_dependencies = match parse_stylesheet(&path) {
+                    Some(dependencies) => Some(
+                        expand_dependencies(dependencies, workspace)
+                            .into_iter()
+                            .map(|dependency| (dependency, None))
+                            .collect::<HashSet<_>>(),
+                    ),
+                    None => parser
+                        .parse_file(&path)
+                        .map(|(module, syntax, source_map)| {
+                            analyze_dependencies(&module, &comments)
+                                .into_iter()
+                                .map(Dependency::new)
+                                .filter(|dependency| dependency.is_external(tsconfig))
+                                .flat_map(|dependency| {
+                                    let import_location = file.as_ref().map(|file| {
+                                        dependency
+                                            .get_import_location(file.to_string(), &source_map)
+                                    });
+
+                                    dependency
+                                        .extract_dependencies(&syntax, workspace)
+                                        .into_iter()
+                                        .map(move |dependency| {
+                                            (dependency, import_location.clone())
+                                        })
+                                })
+                                .collect::<HashSet<_>>()
+                        }),
+                };
 
                 if let (Some(file), Some(file_dependencies)) = (file, file_dependencies) {
-                    dependency_sender.send((file, file_dependencies)).unwrap();
+                    dependency_sender
+                        .send((index, file, file_dependencies))
+                        .unwrap();
                 }
             });
 
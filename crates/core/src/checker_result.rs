@@ -1,8 +1,11 @@
 use crate::config::Config;
+use crate::lockfile::Lockfile;
 use crate::package::{DepsSet, Package};
+use crate::resolver::Resolver;
 use crate::util::is_bin_dependency::is_bin_dependency;
+use crate::workspace::Workspace;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 /// Dependencies checker result.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -10,25 +13,65 @@ use std::collections::{BTreeMap, HashSet};
 pub struct CheckerResult {
     /// Using dependencies in directory. Key is dependency and value is unique file paths.
     pub using_dependencies: BTreeMap<String, HashSet<String>>,
-    /// Missing dependencies in directory. Key is dependency and value is unique file paths.
+    /// Imports of using dependencies in parsed modules. Key is dependency and value is
+    /// every import of it, sorted by file and position.
+    /// Dependencies used by configs, scripts or stylesheets have no imports.
+    pub import_locations: BTreeMap<String, BTreeSet<ImportLocation>>,
+    /// Missing dependencies in directory, except phantom dependencies.
+    /// Key is dependency and value is unique file paths.
     pub missing_dependencies: BTreeMap<String, HashSet<String>>,
+    /// Phantom dependencies in directory, undeclared dependencies which are only installed
+    /// because other dependencies depend on them. Key is dependency and value is unique file paths.
+    pub phantom_dependencies: BTreeMap<String, HashSet<String>>,
     /// Unique unused dependencies.
     pub unused_dependencies: HashSet<String>,
     /// Unique unused dev dependencies.
     pub unused_dev_dependencies: HashSet<String>,
 }
 
+/// An import of a dependency.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportLocation {
+    /// The importing file path.
+    pub file: String,
+    /// The line of the import, starting from 1.
+    pub line: usize,
+    /// The column of the import in characters, starting from 1.
+    pub column: usize,
+    pub kind: ImportKind,
+}
+
+/// Kind of an import.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportKind {
+    /// `import a from "a"`.
+    Import,
+    /// `require("a")` and `import a = require("a")`.
+    Require,
+    /// `import("a")`.
+    DynamicImport,
+    /// `export { a } from "a"` and `export * from "a"`.
+    ExportFrom,
+    /// `import type { A } from "a"` and `import("a").A` in types.
+    ImportType,
+}
+
 impl CheckerResult {
     pub fn new(
         using_dependencies: BTreeMap<String, HashSet<String>>,
+        import_locations: BTreeMap<String, BTreeSet<ImportLocation>>,
         package: Package,
         config: &Config,
+        lockfile: &Lockfile,
+        resolver: &Resolver,
     ) -> CheckerResult {
         let ignore_matches = config
             .get_ignore_matches()
             .expect("Can't get ignore matches");
 
-        let missing_dependencies = if config.skip_missing() {
+        let undeclared_dependencies = if config.skip_missing() {
             BTreeMap::new()
         } else {
             using_dependencies
@@ -36,8 +79,7 @@ impl CheckerResult {
                 .filter(|(dependency, _)| !ignore_matches.is_match(dependency.as_str()))
                 .filter(|(dependency, _)| !package.is_any_dependency(dependency))
                 .filter(|(dependency, _)| {
-                    !config.ignore_bin_package()
-                        || !is_bin_dependency(config.get_directory(), dependency)
+                    !config.ignore_bin_package() || !is_bin_dependency(resolver, dependency)
                 })
                 .map(|(dependency, files)| {
                     (
@@ -45,9 +87,13 @@ impl CheckerResult {
                         files.iter().map(|file| file.to_owned()).collect(),
                     )
                 })
-                .collect()
+                .collect::<BTreeMap<_, _>>()
         };
 
+        let (phantom_dependencies, missing_dependencies) = undeclared_dependencies
+            .into_iter()
+            .partition(|(dependency, _)| lockfile.contains(dependency));
+
         let Package {
             dependencies,
             dev_dependencies,
@@ -59,8 +105,7 @@ impl CheckerResult {
                 .filter(|(dependency, _)| !ignore_matches.is_match(dependency.as_str()))
                 .filter(|(dependency, _)| !using_dependencies.contains_key(dependency.as_str()))
                 .filter(|(dependency, _)| {
-                    !config.ignore_bin_package()
-                        || !is_bin_dependency(config.get_directory(), dependency)
+                    !config.ignore_bin_package() || !is_bin_dependency(resolver, dependency)
                 })
                 .map(|(dependency, _)| dependency)
                 .collect()
@@ -71,9 +116,79 @@ impl CheckerResult {
 
         CheckerResult {
             using_dependencies,
+            import_locations,
             missing_dependencies,
+            phantom_dependencies,
             unused_dependencies,
             unused_dev_dependencies,
         }
     }
 }
+
+/// Dependencies checker result of a package and the members of its workspaces.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspacesCheckerResult {
+    /// Results by workspace directory relative to the root directory, `.` for the root package.
+    pub workspaces: BTreeMap<String, CheckerResult>,
+}
+
+impl WorkspacesCheckerResult {
+    /// Combine results of the root package, which comes first, and the members of its workspaces.
+    ///
+    /// Dependencies of the root package are hoisted, so members may use them without declaring,
+    /// except for sibling workspaces, which are only linked into members declaring them.
+    /// Unused dependencies of the root package are the ones no workspace uses.
+    pub(crate) fn new(results: Vec<(Workspace, CheckerResult)>) -> WorkspacesCheckerResult {
+        let root_package = match results.first() {
+            Some((root, _)) => root.package.clone(),
+            None => return WorkspacesCheckerResult::default(),
+        };
+
+        let workspace_names: HashSet<_> = results
+            .iter()
+            .skip(1)
+            .map(|(workspace, _)| workspace.package.name.as_str())
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+
+        let is_hoisted = |dependency: &str| {
+            root_package.is_any_dependency(dependency) && !workspace_names.contains(dependency)
+        };
+
+        // Root dependencies used by members are hoisted for them, so they aren't unused.
+        let member_dependencies: HashSet<_> = results
+            .iter()
+            .skip(1)
+            .flat_map(|(_, result)| result.using_dependencies.keys())
+            .cloned()
+            .collect();
+
+        let workspaces = results
+            .into_iter()
+            .enumerate()
+            .map(|(index, (workspace, mut result))| {
+                if index == 0 {
+                    result
+                        .unused_dependencies
+                        .retain(|dependency| !member_dependencies.contains(dependency));
+                    result
+                        .unused_dev_dependencies
+                        .retain(|dependency| !member_dependencies.contains(dependency));
+                } else {
+                    result
+                        .missing_dependencies
+                        .retain(|dependency, _| !is_hoisted(dependency));
+                    result
+                        .phantom_dependencies
+                        .retain(|dependency, _| !is_hoisted(dependency));
+                }
+
+                (workspace.get_name(), result)
+            })
+            .collect();
+
+        WorkspacesCheckerResult { workspaces }
+    }
+}
@@ -43,6 +43,12 @@ pub struct Config {
     skip_missing: bool,
     /// Path to a file with patterns describing files to ignore.
     ignore_path: Option<PathBuf>,
+    /// A flag to indicate if depcheck parses every JavaScript file as Flow typed,
+    /// not only files with a `@flow` pragma.
+    flow: bool,
+    /// The Node version or range core modules are checked against.
+    /// Defaults to `engines.node` of the package, or else to the latest version.
+    node_version: Option<String>,
 }
 
 impl Config {
@@ -84,11 +90,18 @@ impl Config {
             ignore_bin_package: false,
             ignore_matches: Vec::new(),
             ignore_path: None,
+            flow: false,
+            node_version: None,
         }
     }
 }
 
 impl Config {
+    pub fn with_directory(mut self, directory: PathBuf) -> Self {
+        self.directory = directory;
+        self
+    }
+
     pub fn with_ignore_patterns(mut self, ignore_patterns: Vec<String>) -> Self {
         self.ignore_patterns = ignore_patterns;
         self
@@ -114,6 +127,16 @@ impl Config {
         self
     }
 
+    pub fn with_flow(mut self, flow: bool) -> Self {
+        self.flow = flow;
+        self
+    }
+
+    pub fn with_node_version(mut self, node_version: Option<String>) -> Self {
+        self.node_version = node_version;
+        self
+    }
+
     pub fn ignore_bin_package(&self) -> bool {
         self.ignore_bin_package
     }
@@ -125,6 +148,14 @@ impl Config {
     pub fn ignore_path(&self) -> Option<&Path> {
         self.ignore_path.as_deref()
     }
+
+    pub fn flow(&self) -> bool {
+        self.flow
+    }
+
+    pub fn node_version(&self) -> Option<&str> {
+        self.node_version.as_deref()
+    }
 }
 
 impl Config {
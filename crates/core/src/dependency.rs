@@ -1,12 +1,19 @@
-use crate::config::Config;
+use crate::checker_result::{ImportKind, ImportLocation};
 use crate::package::Package;
+use crate::tsconfig::TsConfig;
+use crate::util::extract_loader_name::extract_loader_name;
+use crate::util::extract_package_name::extract_package_name;
 use crate::util::extract_type_name::extract_type_name;
 use crate::util::is_bin_dependency::is_bin_dependency;
 use crate::util::is_core_module::is_core_module;
-use crate::util::load_module::load_module;
-use regex::Regex;
+use crate::util::resolve_package_map::resolve_package_map;
+use crate::util::split_webpack_request::split_webpack_request;
+use crate::workspace::Workspace;
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::iter;
 use std::path::{Component, PathBuf};
+use swc_common::SourceMap;
 use swc_ecma_dep_graph::{DependencyDescriptor, DependencyKind};
 use swc_ecma_parser::Syntax;
 
@@ -22,52 +29,14 @@ impl Dependency {
 }
 
 impl Dependency {
-    pub fn extract_dependencies(
-        self,
-        syntax: &Syntax,
-        package: &Package,
-        config: &Config,
-    ) -> Vec<String> {
-        self.get_dependencies(syntax, package)
-            .into_iter()
-            .filter(|dependency| !is_core_module(dependency.as_str()))
-            .filter(|dependency| {
-                !config.ignore_bin_package()
-                    || !is_bin_dependency(config.get_directory(), dependency)
-            })
-            .flat_map(|dependency| {
-                let dependency_module = load_module(
-                    &config
-                        .get_directory()
-                        .join("node_modules")
-                        .join(&dependency),
-                );
-                dependency_module
-                    .map(|dependency_module| {
-                        iter::once(&dependency)
-                            .chain(dependency_module.peer_dependencies.keys().filter(
-                                |&peer_dependency| {
-                                    package.is_dependency(peer_dependency)
-                                        || package.is_dev_dependency(peer_dependency)
-                                },
-                            ))
-                            .chain(dependency_module.optional_dependencies.keys().filter(
-                                |&optional_dependency| {
-                                    package.is_dependency(optional_dependency)
-                                        || package.is_dev_dependency(optional_dependency)
-                                },
-                            ))
-                            .cloned()
-                            .collect()
-                    })
-                    .unwrap_or_else(|_| vec![dependency])
-            })
-            .collect()
+    pub fn extract_dependencies(self, syntax: &Syntax, workspace: &Workspace) -> Vec<String> {
+        expand_dependencies(self.get_dependencies(syntax, &workspace.package), workspace)
     }
 
     fn get_dependencies(&self, syntax: &Syntax, package: &Package) -> Vec<String> {
-        self.get_dependency()
-            .map(|dependency| match syntax {
+        self.get_request_dependencies(package)
+            .into_iter()
+            .flat_map(|dependency| match syntax {
                 Syntax::Typescript(_) => {
                     if self.descriptor.kind == DependencyKind::ImportType {
                         let dependency_type = format!("@types/{dependency}");
@@ -93,33 +62,123 @@ impl Dependency {
                     vec![dependency]
                 }
             })
-            .unwrap_or_default()
+            .collect()
     }
 
-    fn get_dependency(&self) -> Option<String> {
-        let specifier = self.descriptor.specifier.to_string();
-        let scope_pattern = Regex::new(r"^(?:(@[^/]+)[/]+)([^/]+)[/]?").unwrap();
-        let base_pattern = Regex::new(r"^([^/]+)[/]?").unwrap();
-        let scope_pattern_test = Regex::new(r"^@").unwrap();
+    /// Get packages of a request, including webpack inline loaders,
+    /// e.g. `style-loader` and `css-loader` from `style-loader!css-loader!./a.css`.
+    fn get_request_dependencies(&self, package: &Package) -> Vec<String> {
+        // Subpath imports are local, unless the `imports` field maps them to packages.
+        if self.descriptor.specifier.starts_with('#') {
+            return get_mapped_dependencies(&package.imports, &self.descriptor.specifier);
+        }
 
-        if scope_pattern_test.is_match(&specifier) {
-            let captures = scope_pattern.captures(&specifier)?;
+        let mut requests = split_webpack_request(&self.descriptor.specifier);
+        let resource = requests
+            .pop()
+            .filter(|resource| !resource.starts_with('.') && !resource.starts_with('/'));
 
-            return match (captures.get(1), captures.get(2)) {
-                (Some(first), Some(second)) => {
-                    Some(first.as_str().to_owned() + "/" + second.as_str())
-                }
-                _ => None,
-            };
-        } else {
-            let captures = base_pattern.captures(&specifier)?;
-            captures.get(1).map(|v| v.as_str().to_owned())
+        let loader_dependencies = requests
+            .into_iter()
+            .filter_map(|loader| extract_loader_name(loader, package));
+
+        loader_dependencies
+            .chain(resource.map_or_else(Vec::new, |resource| {
+                get_resource_dependencies(resource, package)
+            }))
+            .collect()
+    }
+
+    /// Get the location of the import in a file parsed with the source map.
+    pub fn get_import_location(&self, file: String, source_map: &SourceMap) -> ImportLocation {
+        let location = source_map.lookup_char_pos(self.descriptor.span.lo);
+
+        let kind = match self.descriptor.kind {
+            DependencyKind::Import if self.descriptor.is_dynamic => ImportKind::DynamicImport,
+            DependencyKind::Import => ImportKind::Import,
+            DependencyKind::ImportType => ImportKind::ImportType,
+            DependencyKind::Require | DependencyKind::ImportEquals => ImportKind::Require,
+            DependencyKind::Export | DependencyKind::ExportType => ImportKind::ExportFrom,
+        };
+
+        ImportLocation {
+            file,
+            line: location.line,
+            column: location.col.0 + 1,
+            kind,
         }
     }
 
-    pub fn is_external(&self) -> bool {
+    /// Check if a dependency is a package rather than a project file,
+    /// which includes files imported through `paths` and `baseUrl` of `tsconfig.json`.
+    pub fn is_external(&self, tsconfig: &TsConfig) -> bool {
         let path = PathBuf::from(self.descriptor.specifier.to_string());
         let root_component = path.components().next();
         matches!(root_component, Some(Component::Normal(_)))
+            && !tsconfig.is_local_specifier(&self.descriptor.specifier)
+    }
+}
+
+/// Get the package of a resource. A package importing itself by its name is internal,
+/// so these imports are resolved through its own `exports` instead.
+fn get_resource_dependencies(resource: &str, package: &Package) -> Vec<String> {
+    match extract_package_name(resource) {
+        Some(dependency) if !package.name.is_empty() && dependency == package.name => {
+            let subpath = format!(".{}", &resource[dependency.len()..]);
+            get_mapped_dependencies(&package.get_exports(), &subpath)
+        }
+        dependency => dependency.into_iter().collect(),
     }
 }
+
+/// Get packages an `imports` or `exports` map resolves a specifier to.
+/// Targets relative to the package are local files.
+fn get_mapped_dependencies(map: &BTreeMap<String, Value>, specifier: &str) -> Vec<String> {
+    resolve_package_map(map, specifier)
+        .into_iter()
+        .filter(|target| !target.starts_with('.') && !target.starts_with('/'))
+        .filter_map(|target| extract_package_name(&target))
+        .collect()
+}
+
+/// Drop core modules and ignored bin packages from dependencies and add
+/// the peer and optional dependencies they bring along.
+pub fn expand_dependencies(dependencies: Vec<String>, workspace: &Workspace) -> Vec<String> {
+    let Workspace {
+        config,
+        package,
+        resolver,
+        node_version,
+        ..
+    } = workspace;
+
+    dependencies
+        .into_iter()
+        .filter(|dependency| !is_core_module(dependency.as_str(), *node_version))
+        .filter(|dependency| {
+            !config.ignore_bin_package() || !is_bin_dependency(resolver, dependency)
+        })
+        .flat_map(|dependency| {
+            let dependency_module = resolver.load_module(&dependency);
+            dependency_module
+                .map(|dependency_module| {
+                    iter::once(&dependency)
+                        .chain(dependency_module.peer_dependencies.keys().filter(
+                            |&peer_dependency| {
+                                package.is_dependency(peer_dependency)
+                                    || package.is_dev_dependency(peer_dependency)
+                            },
+                        ))
+                        .chain(dependency_module.optional_dependencies.keys().filter(
+                            |&optional_dependency| {
+                                package.is_dependency(optional_dependency)
+                                    || package.is_dev_dependency(optional_dependency)
+                            },
+                        ))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_else(|_| vec![dependency])
+        })
+        .collect()
+}
@@ -2,6 +2,12 @@ pub mod checker;
 pub mod checker_result;
 pub mod config;
 pub(crate) mod dependency;
+pub(crate) mod lockfile;
 pub(crate) mod package;
 pub(crate) mod parser;
+pub(crate) mod resolver;
+pub(crate) mod special;
+pub(crate) mod stylesheet;
+pub(crate) mod tsconfig;
 pub(crate) mod util;
+pub(crate) mod workspace;
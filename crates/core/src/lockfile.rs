@@ -0,0 +1,165 @@
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Lockfiles of npm, yarn and pnpm, in the order they are looked up.
+const LOCKFILES: [&str; 4] = [
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+];
+
+/// Packages installed by a package manager, direct or transitive.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Lockfile {
+    packages: HashSet<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageLock {
+    /// Packages by their location in node_modules, lockfile version 2 and 3.
+    #[serde(default)]
+    packages: BTreeMap<String, PackageLockPackage>,
+    /// Nested packages by their name, lockfile version 1.
+    #[serde(default)]
+    dependencies: BTreeMap<String, PackageLockDependency>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageLockPackage {
+    /// A flag to indicate if the package is a link to a workspace.
+    #[serde(default)]
+    link: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageLockDependency {
+    #[serde(default)]
+    dependencies: BTreeMap<String, PackageLockDependency>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PnpmLock {
+    #[serde(default)]
+    packages: BTreeMap<String, IgnoredAny>,
+}
+
+impl Lockfile {
+    /// Load the lockfile of a directory, `package-lock.json`, `npm-shrinkwrap.json`,
+    /// `yarn.lock` or `pnpm-lock.yaml`.
+    pub fn from_directory(directory: &Path) -> Option<Self> {
+        let path = LOCKFILES
+            .iter()
+            .map(|file| directory.join(file))
+            .find(|path| path.is_file())?;
+
+        Lockfile::from_path(&path)
+            .map_err(|error| {
+                log::error!("failed to load lockfile {:#?}: {:#?}", path, error);
+            })
+            .ok()
+    }
+
+    pub fn from_path(path: &Path) -> eyre::Result<Self> {
+        let content = fs::read_to_string(path)?;
+
+        let packages = match path.file_name().and_then(|file_name| file_name.to_str()) {
+            Some("yarn.lock") => parse_yarn_lock(&content),
+            Some("pnpm-lock.yaml") => parse_pnpm_lock(&content)?,
+            _ => parse_package_lock(&content)?,
+        };
+
+        Ok(Lockfile { packages })
+    }
+
+    /// Check if a package is installed.
+    pub fn contains(&self, package: &str) -> bool {
+        self.packages.contains(package)
+    }
+}
+
+fn parse_package_lock(content: &str) -> eyre::Result<HashSet<String>> {
+    let package_lock: PackageLock = serde_json::from_str(content)?;
+
+    let mut packages: HashSet<_> = package_lock
+        .packages
+        .iter()
+        .filter(|(_, package)| !package.link)
+        .filter_map(|(location, _)| {
+            location
+                .rsplit_once("node_modules/")
+                .map(|(_, name)| name.to_owned())
+        })
+        .collect();
+
+    let mut dependencies: Vec<_> = package_lock.dependencies.iter().collect();
+    while let Some((name, dependency)) = dependencies.pop() {
+        packages.insert(name.to_owned());
+        dependencies.extend(dependency.dependencies.iter());
+    }
+
+    Ok(packages)
+}
+
+/// Get packages of a yarn lockfile. Both classic lockfiles and the YAML lockfiles of
+/// yarn berry start entries with unindented lines listing descriptors, e.g.
+/// `"@babel/core@^7.0.0", "@babel/core@^7.1.0":`.
+fn parse_yarn_lock(content: &str) -> HashSet<String> {
+    content
+        .lines()
+        .filter(|line| !line.starts_with(char::is_whitespace) && !line.starts_with('#'))
+        .filter_map(|line| line.trim_end().strip_suffix(':'))
+        .flat_map(|descriptors| descriptors.split(','))
+        .map(|descriptor| descriptor.trim().trim_matches('"'))
+        .filter_map(|descriptor| {
+            let separator = descriptor.get(1..)?.find('@')? + 1;
+            let (name, range) = descriptor.split_at(separator);
+
+            // Workspaces of yarn berry are listed next to the installed packages.
+            if range.starts_with("@workspace:") {
+                None
+            } else {
+                Some(name.to_owned())
+            }
+        })
+        .collect()
+}
+
+fn parse_pnpm_lock(content: &str) -> eyre::Result<HashSet<String>> {
+    let pnpm_lock: PnpmLock = serde_yaml::from_str(content)?;
+
+    let packages = pnpm_lock
+        .packages
+        .keys()
+        .filter_map(|key| get_pnpm_package_name(key))
+        .collect();
+
+    Ok(packages)
+}
+
+/// Get a package name from a key of pnpm packages, e.g. `@babel/core` from `/@babel/core/7.0.0`,
+/// `/@babel/core@7.0.0` or `@babel/core@7.0.0(supports-color@8.1.1)`.
+fn get_pnpm_package_name(key: &str) -> Option<String> {
+    let key = key.trim_start_matches('/');
+
+    let (scope, name) = match key.strip_prefix('@') {
+        Some(scoped_key) => {
+            let (scope, name) = scoped_key.split_once('/')?;
+            (Some(scope), name)
+        }
+        None => (None, key),
+    };
+
+    let name = name
+        .split(|character| matches!(character, '@' | '/' | '('))
+        .next()
+        .filter(|name| !name.is_empty())?;
+
+    match scope {
+        Some(scope) => Some(format!("@{scope}/{name}")),
+        None => Some(name.to_owned()),
+    }
+}
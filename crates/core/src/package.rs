@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::BTreeMap;
 use std::fs;
 use std::io::Read;
@@ -8,6 +9,41 @@ use std::path::Path;
 pub type DepsSet = BTreeMap<String, String>;
 /// An ordered map for `bin` entries.
 pub type BinSet = BTreeMap<String, String>;
+/// An ordered map for `imports` entries.
+pub type ImportsSet = BTreeMap<String, Value>;
+/// An ordered map for `exports` entries.
+pub type ExportsSet = BTreeMap<String, Value>;
+/// An ordered map for `scripts` entries.
+pub type ScriptsSet = BTreeMap<String, String>;
+
+/// A `bin` entry, either a single binary named after the package or a set of binaries.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Bin {
+    Single(String),
+    Multiple(BinSet),
+}
+
+/// A `workspaces` entry, either an array of patterns or an object with `packages` patterns.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Workspaces {
+    Packages(Vec<String>),
+    Config {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+impl Workspaces {
+    /// Get patterns of workspace directories.
+    pub fn get_packages(&self) -> &[String] {
+        match self {
+            Workspaces::Packages(packages) => packages,
+            Workspaces::Config { packages } => packages,
+        }
+    }
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,7 +71,35 @@ pub struct Package {
     pub optional_dependencies: DepsSet,
     /// The optional set of binary definitions.
     #[serde(default)]
-    pub bin: Option<BinSet>,
+    pub bin: Option<Bin>,
+    /// The optional set of supported engine versions. Legacy manifests declare an array
+    /// like `["node >= 0.8"]`, so it isn't required to be a map of strings.
+    #[serde(default)]
+    pub engines: Option<Value>,
+    /// The optional exports, either a single target, conditions or a set of subpath exports.
+    #[serde(default)]
+    pub exports: Option<Value>,
+    /// The optional set of subpath imports.
+    #[serde(default)]
+    pub imports: ImportsSet,
+    /// The optional set of script commands.
+    #[serde(default)]
+    pub scripts: ScriptsSet,
+    /// The optional Babel configuration.
+    #[serde(default)]
+    pub babel: Option<Value>,
+    /// The optional ESLint configuration.
+    #[serde(default)]
+    pub eslint_config: Option<Value>,
+    /// The optional Jest configuration.
+    #[serde(default)]
+    pub jest: Option<Value>,
+    /// The optional Mocha configuration.
+    #[serde(default)]
+    pub mocha: Option<Value>,
+    /// The optional workspaces patterns.
+    #[serde(default)]
+    pub workspaces: Option<Workspaces>,
 }
 
 impl Package {
@@ -78,6 +142,26 @@ impl Package {
         self.optional_dependencies.contains_key(dependency)
     }
 
+    /// Get exports as a set of subpath exports, e.g. `{ ".": "./index.js" }` for `"./index.js"`.
+    pub fn get_exports(&self) -> ExportsSet {
+        match &self.exports {
+            Some(Value::Object(exports)) if exports.keys().any(|key| key.starts_with('.')) => {
+                exports.clone().into_iter().collect()
+            }
+            Some(exports) => ExportsSet::from([(String::from("."), exports.clone())]),
+            None => ExportsSet::new(),
+        }
+    }
+
+    /// Get names of dependencies, development, peer and optional dependencies.
+    pub fn get_dependency_names(&self) -> impl Iterator<Item = &String> {
+        self.dependencies
+            .keys()
+            .chain(self.dev_dependencies.keys())
+            .chain(self.peer_dependencies.keys())
+            .chain(self.optional_dependencies.keys())
+    }
+
     pub fn is_any_dependency(&self, dependency: &str) -> bool {
         self.is_dependency(dependency)
             || self.is_dev_dependency(dependency)
@@ -1,75 +1,169 @@
+use std::fs;
 use std::path::Path;
 
+use once_cell::sync::Lazy;
+use regex::Regex;
 use swc_common::comments::SingleThreadedComments;
 use swc_common::errors::{ColorConfig, Handler};
 use swc_common::sync::Lrc;
-use swc_common::SourceMap;
+use swc_common::{FileName, SourceMap};
 use swc_ecma_ast::Module;
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::{EsConfig, StringInput, Syntax, TsConfig};
 
+use crate::util::extract_scripts::extract_scripts;
+use crate::util::strip_flow_syntax::strip_flow_syntax;
+
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
-pub struct Parser {}
+pub struct Parser {
+    /// Parse every JavaScript file as Flow typed, not only files with a `@flow` pragma.
+    flow: bool,
+}
+
+impl Parser {
+    pub fn with_flow(mut self, flow: bool) -> Self {
+        self.flow = flow;
+        self
+    }
+}
 
 impl Parser {
-    pub fn parse_file(&self, file: &Path) -> Option<(Module, Syntax)> {
+    /// Parse a module, along with the source map to look up positions of its spans.
+    pub fn parse_file(&self, file: &Path) -> Option<(Module, Syntax, Lrc<SourceMap>)> {
         let extension = file.extension()?.to_str()?;
 
         log::debug!("parse file {:#?}", file);
 
-        let syntax = match extension {
-            "ts" | "tsx" | "cts" | "mts" => Syntax::Typescript(TsConfig {
-                dts: file.ends_with(".d.ts"),
-                tsx: extension == "tsx",
-                decorators: true,
-                no_early_errors: true,
-            }),
-            "mjs" | "js" | "jsx" | "cjs" => Syntax::Es(EsConfig {
-                jsx: true,
-                fn_bind: true,
-                decorators: true,
-                decorators_before_export: true,
-                export_default_from: true,
-                import_assertions: true,
-                private_in_object: true,
-                allow_super_outside_method: true,
-                allow_return_outside_function: true,
-            }),
+        let (syntax, source) = match extension {
+            "ts" | "tsx" | "cts" | "mts" => (
+                typescript_syntax(extension == "tsx", file.ends_with(".d.ts")),
+                load_source(file)?,
+            ),
+            "mjs" | "js" | "jsx" | "cjs" => (es_syntax(), load_source(file)?),
+            "vue" | "svelte" => load_component(file)?,
             _ => return None,
         };
 
-        let cm: Lrc<SourceMap> = Default::default();
-        let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
+        // Flow is a JavaScript dialect, so its dependencies are extracted like JavaScript ones,
+        // but its type annotations can only be parsed by the TypeScript parser.
+        if matches!(syntax, Syntax::Es(_)) && (self.flow || has_flow_pragma(&source)) {
+            let source = strip_flow_syntax(&source);
 
-        let fm = cm
-            .load_file(file)
-            .map_err(|error| {
-                log::error!("failed to load {:#?}", error);
-            })
-            .ok()?;
+            // Generic arrow functions, `<T>(value: T) => value`, can't be parsed with JSX,
+            // so JSX is only enabled for `.jsx` files and files failing to parse without it.
+            if extension != "jsx" {
+                if let Some((module, cm)) =
+                    parse_source(file, typescript_syntax(false, false), source.clone(), false)
+                {
+                    return Some((module, syntax, cm));
+                }
+            }
 
-        let comments = SingleThreadedComments::default();
-        let lexer = Lexer::new(
-            syntax.to_owned(),
-            Default::default(),
-            StringInput::from(&*fm),
-            Some(&comments),
-        );
+            let (module, cm) = parse_source(file, typescript_syntax(true, false), source, true)?;
+            return Some((module, syntax, cm));
+        }
 
-        let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+        let (module, cm) = parse_source(file, syntax, source, true)?;
 
-        for error in parser.take_errors() {
-            error.into_diagnostic(&handler).emit();
-        }
+        Some((module, syntax, cm))
+    }
+}
+
+/// Parse a module source with a syntax. Without emitting errors, the parsing also fails
+/// on errors the parser recovers from, so another syntax can be tried.
+fn parse_source(
+    file: &Path,
+    syntax: Syntax,
+    source: String,
+    emit_errors: bool,
+) -> Option<(Module, Lrc<SourceMap>)> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
+
+    let fm = cm.new_source_file(FileName::Real(file.to_path_buf()), source);
 
-        let module: Module = parser
-            .parse_module()
-            .map_err(|e| e.into_diagnostic(&handler).emit())
-            .map_err(|error| {
-                log::error!("failed to parser module {:#?}", error);
-            })
-            .ok()?;
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        syntax,
+        Default::default(),
+        StringInput::from(&*fm),
+        Some(&comments),
+    );
 
-        Some((module, syntax.to_owned()))
+    let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+
+    let result = parser.parse_module();
+
+    if !emit_errors {
+        let has_errors = !parser.take_errors().is_empty();
+        return result
+            .ok()
+            .filter(|_| !has_errors)
+            .map(|module| (module, cm));
     }
+
+    let module: Module = result
+        .map_err(|e| e.into_diagnostic(&handler).emit())
+        .map_err(|error| {
+            log::error!("failed to parse module {:#?}: {:#?}", file, error);
+        })
+        .ok()?;
+
+    Some((module, cm))
+}
+
+fn load_source(file: &Path) -> Option<String> {
+    fs::read_to_string(file)
+        .map_err(|error| {
+            log::error!("failed to load {:#?}", error);
+        })
+        .ok()
+}
+
+/// Load script blocks of a single-file component with a syntax matching their `lang`.
+fn load_component(file: &Path) -> Option<(Syntax, String)> {
+    let scripts = extract_scripts(&load_source(file)?);
+
+    let syntax = match scripts.lang.as_deref() {
+        Some("ts") => typescript_syntax(false, false),
+        Some("tsx") => typescript_syntax(true, false),
+        _ => es_syntax(),
+    };
+
+    Some((syntax, scripts.source))
+}
+
+/// Check if the leading comments of a source contain a `@flow` pragma.
+fn has_flow_pragma(source: &str) -> bool {
+    static LEADING_COMMENTS_PATTERN: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^(?:\s|#![^\n]*|//[^\n]*|/\*(?s:.*?)\*/)*").unwrap());
+    static FLOW_PRAGMA_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"@flow\b").unwrap());
+
+    LEADING_COMMENTS_PATTERN
+        .find(source)
+        .map(|comments| FLOW_PRAGMA_PATTERN.is_match(comments.as_str()))
+        .unwrap_or(false)
+}
+
+fn typescript_syntax(tsx: bool, dts: bool) -> Syntax {
+    Syntax::Typescript(TsConfig {
+        dts,
+        tsx,
+        decorators: true,
+        no_early_errors: true,
+    })
+}
+
+fn es_syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        jsx: true,
+        fn_bind: true,
+        decorators: true,
+        decorators_before_export: true,
+        export_default_from: true,
+        import_assertions: true,
+        private_in_object: true,
+        allow_super_outside_method: true,
+        allow_return_outside_function: true,
+    })
 }
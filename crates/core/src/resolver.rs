@@ -0,0 +1,418 @@
+use eyre::{eyre, WrapErr};
+use regex::Regex;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use zip::ZipArchive;
+
+use crate::package::Package;
+use crate::util::load_module::load_module;
+
+/// Files with the Plug'n'Play data of Yarn, in the order they are looked up.
+const PNP_FILES: [&str; 3] = [".pnp.data.json", ".pnp.cjs", ".pnp.js"];
+
+/// Resolves the package json of dependencies installed for a directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Resolver {
+    /// Dependencies installed into node_modules of the directory.
+    NodeModules(PathBuf),
+    /// Dependencies installed by Yarn Plug'n'Play, without node_modules.
+    Pnp(PnpResolver),
+    /// Dependencies installed by pnpm into its virtual store, `node_modules/.pnpm`,
+    /// and linked into node_modules of the directory.
+    Pnpm(PnpmResolver),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PnpResolver {
+    /// Locations of installed packages by their names and references.
+    locations: HashMap<(String, String), PathBuf>,
+    /// Names and references of packages by the dependency names the directory requires them with.
+    dependencies: HashMap<String, (String, String)>,
+    /// The first installed reference of packages by their names,
+    /// for dependencies the directory doesn't declare.
+    references: HashMap<String, String>,
+    manifests: ManifestCache,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PnpmResolver {
+    /// The directory dependencies are resolved for.
+    directory: PathBuf,
+    /// The virtual store of the directory or its ancestors.
+    virtual_store: PathBuf,
+    /// Entries of the virtual store by the names of their packages, from the highest version.
+    entries: Arc<HashMap<String, Vec<String>>>,
+}
+
+/// Manifests of loaded dependencies, shared by clones of a resolver.
+#[derive(Clone, Debug, Default)]
+struct ManifestCache(Arc<Mutex<HashMap<String, Option<Package>>>>);
+
+impl PartialEq for ManifestCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ManifestCache {}
+
+/// Information of the installed references of a package.
+type PnpReferences = Vec<(Option<String>, PnpPackageInformation)>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PnpData {
+    /// Installed references of packages by their names.
+    #[serde(default)]
+    package_registry_data: Vec<(Option<String>, PnpReferences)>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PnpPackageInformation {
+    package_location: String,
+    #[serde(default)]
+    package_dependencies: Vec<(String, Option<PnpReference>)>,
+}
+
+/// A dependency reference, either a reference of the package with the dependency name
+/// or an alias to another package.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PnpReference {
+    Reference(String),
+    Alias(String, String),
+}
+
+impl Resolver {
+    /// Get a resolver for a directory, using the Plug'n'Play data of Yarn
+    /// or the virtual store of pnpm from the directory or its ancestors when there is any.
+    pub fn from_directory(directory: &Path) -> Self {
+        let canonical_directory = match directory.canonicalize() {
+            Ok(canonical_directory) => canonical_directory,
+            Err(_) => return Resolver::NodeModules(directory.to_path_buf()),
+        };
+
+        let pnp_path = canonical_directory
+            .ancestors()
+            .flat_map(|ancestor| PNP_FILES.iter().map(move |file| ancestor.join(file)))
+            .find(|path| path.is_file());
+
+        if let Some(path) = pnp_path {
+            match PnpResolver::from_path(&canonical_directory, &path) {
+                Ok(pnp_resolver) => return Resolver::Pnp(pnp_resolver),
+                Err(error) => log::error!("failed to load {:#?}: {:#?}", path, error),
+            }
+        }
+
+        let virtual_store = canonical_directory
+            .ancestors()
+            .map(|ancestor| ancestor.join("node_modules").join(".pnpm"))
+            .find(|path| path.is_dir());
+
+        match virtual_store {
+            Some(virtual_store) => {
+                Resolver::Pnpm(PnpmResolver::new(canonical_directory, virtual_store))
+            }
+            None => Resolver::NodeModules(directory.to_path_buf()),
+        }
+    }
+
+    /// Get the package json of an installed dependency.
+    pub fn load_module(&self, dependency: &str) -> eyre::Result<Package> {
+        match self {
+            Resolver::NodeModules(directory) => {
+                load_module(&directory.join("node_modules").join(dependency))
+            }
+            Resolver::Pnp(pnp_resolver) => pnp_resolver.load_module(dependency),
+            Resolver::Pnpm(pnpm_resolver) => {
+                let location = pnpm_resolver
+                    .resolve(dependency)
+                    .ok_or_else(|| eyre!("Failed to resolve {dependency}"))?;
+
+                load_module(&location)
+            }
+        }
+    }
+}
+
+impl PnpmResolver {
+    fn new(directory: PathBuf, virtual_store: PathBuf) -> Self {
+        let mut entries: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+        for entry in fs::read_dir(&virtual_store).into_iter().flatten().flatten() {
+            let entry = entry.file_name().to_string_lossy().into_owned();
+            if let Some((name, version)) = parse_virtual_store_entry(&entry) {
+                entries.entry(name).or_default().push((version, entry));
+            }
+        }
+
+        let entries = entries
+            .into_iter()
+            .map(|(name, mut versions)| {
+                versions.sort_by(|(a, _), (b, _)| compare_versions(b, a));
+                let entries = versions.into_iter().map(|(_, entry)| entry).collect();
+                (name, entries)
+            })
+            .collect();
+
+        PnpmResolver {
+            directory,
+            virtual_store,
+            entries: Arc::new(entries),
+        }
+    }
+
+    /// Get the real location of a dependency in the virtual store. Dependencies
+    /// the directory doesn't declare, like transitive ones, aren't linked into its node_modules,
+    /// so they are looked up in the hoisted node_modules of the virtual store
+    /// and then in the virtual store itself, from the highest installed version.
+    fn resolve(&self, dependency: &str) -> Option<PathBuf> {
+        let linked_locations = [
+            self.directory.join("node_modules").join(dependency),
+            self.virtual_store.join("node_modules").join(dependency),
+        ];
+
+        if let Some(location) = linked_locations
+            .iter()
+            .find(|location| location.join("package.json").is_file())
+        {
+            return location.canonicalize().ok();
+        }
+
+        self.entries
+            .get(dependency)?
+            .iter()
+            .map(|entry| {
+                self.virtual_store
+                    .join(entry)
+                    .join("node_modules")
+                    .join(dependency)
+            })
+            .find(|location| location.join("package.json").is_file())
+    }
+}
+
+/// Get the package name and version of a virtual store entry. Packages are stored as
+/// `<name>@<version><peers suffix>/node_modules/<name>`, with the `/` of scoped names
+/// replaced with `+`, and the suffix like `_react@18.2.0` or `(react@18.2.0)`.
+fn parse_virtual_store_entry(entry: &str) -> Option<(String, String)> {
+    let separator = entry.get(1..)?.find('@')? + 1;
+    let (name, version) = entry.split_at(separator);
+    let version = version[1..]
+        .split(|character| character == '_' || character == '(')
+        .next()?;
+
+    Some((name.replace('+', "/"), version.to_owned()))
+}
+
+/// Compare versions by their precedence, e.g. `1.10.0` is higher than `1.9.0`
+/// and `2.0.0-rc.1` is lower than `2.0.0`.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn get_precedence(version: &str) -> (Vec<u64>, bool, &str) {
+        let version = version.split('+').next().unwrap_or_default();
+        let (release, prerelease) = version.split_once('-').unwrap_or((version, ""));
+        let release = release
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect();
+
+        (release, prerelease.is_empty(), prerelease)
+    }
+
+    get_precedence(a).cmp(&get_precedence(b))
+}
+
+impl PnpResolver {
+    fn from_path(directory: &Path, path: &Path) -> eyre::Result<Self> {
+        let data = load_pnp_data(path)?;
+        let root = path
+            .parent()
+            .ok_or_else(|| eyre!("Failed to get directory of {:?}", path))?;
+
+        let mut locations = HashMap::new();
+        let mut references = HashMap::new();
+        let mut issuer_dependencies = None;
+
+        for (name, package_references) in data.package_registry_data {
+            let name = name.unwrap_or_default();
+
+            for (reference, information) in package_references {
+                let reference = reference.unwrap_or_default();
+                let location = resolve_virtual_location(&root.join(&information.package_location));
+
+                if issuer_dependencies.is_none() && location == directory {
+                    issuer_dependencies =
+                        Some(get_pnp_dependencies(information.package_dependencies));
+                }
+
+                references
+                    .entry(name.clone())
+                    .or_insert_with(|| reference.clone());
+                locations.insert((name.clone(), reference), location);
+            }
+        }
+
+        Ok(PnpResolver {
+            locations,
+            dependencies: issuer_dependencies.unwrap_or_default(),
+            references,
+            manifests: ManifestCache::default(),
+        })
+    }
+
+    /// Get the location of a dependency, as required from the directory.
+    fn resolve(&self, dependency: &str) -> Option<&PathBuf> {
+        match self.dependencies.get(dependency) {
+            Some(package) => self.locations.get(package),
+            // Dependencies the directory doesn't declare resolve to any installed version.
+            None => {
+                let reference = self.references.get(dependency)?;
+                self.locations
+                    .get(&(dependency.to_owned(), reference.to_owned()))
+            }
+        }
+    }
+
+    /// Get the package json of a dependency, loading it once.
+    fn load_module(&self, dependency: &str) -> eyre::Result<Package> {
+        if let Some(manifest) = self.manifests.0.lock().unwrap().get(dependency) {
+            return manifest
+                .clone()
+                .ok_or_else(|| eyre!("Failed to load {dependency}"));
+        }
+
+        let manifest = self
+            .resolve(dependency)
+            .ok_or_else(|| eyre!("Failed to resolve {dependency}"))
+            .and_then(|location| {
+                load_archived_module(location).unwrap_or_else(|| load_module(location))
+            });
+
+        self.manifests
+            .0
+            .lock()
+            .unwrap()
+            .insert(dependency.to_owned(), manifest.as_ref().ok().cloned());
+
+        manifest
+    }
+}
+
+/// Get names and references of packages by the dependency names they are required with.
+fn get_pnp_dependencies(
+    dependencies: Vec<(String, Option<PnpReference>)>,
+) -> HashMap<String, (String, String)> {
+    dependencies
+        .into_iter()
+        .filter_map(|(dependency, reference)| match reference? {
+            PnpReference::Reference(reference) => {
+                Some((dependency.clone(), (dependency, reference)))
+            }
+            PnpReference::Alias(name, reference) => Some((dependency, (name, reference))),
+        })
+        .collect()
+}
+
+/// Map a location inside the virtual directory of Yarn to the real location,
+/// e.g. `.yarn/__virtual__/host-virtual-4b1c2d3e5f/0/cache/host.zip` to `.yarn/cache/host.zip`.
+///
+/// Packages with peer dependencies get a virtual location for each set of peers,
+/// `<base>/__virtual__/<hash>/<depth>/<path>`, which is `<path>` relative to `<depth>`
+/// levels above `<base>`.
+fn resolve_virtual_location(location: &Path) -> PathBuf {
+    let components: Vec<_> = location.components().collect();
+    let virtual_index = components
+        .iter()
+        .position(|component| *component == Component::Normal("__virtual__".as_ref()));
+
+    let (virtual_index, depth) = match virtual_index.and_then(|index| {
+        let depth = components.get(index + 2)?.as_os_str().to_str()?;
+        Some((index, depth.parse::<usize>().ok()?))
+    }) {
+        Some(virtual_location) => virtual_location,
+        None => return location.to_path_buf(),
+    };
+
+    let mut real_location: PathBuf = components[..virtual_index].iter().collect();
+    for _ in 0..depth {
+        real_location.pop();
+    }
+
+    real_location.extend(&components[virtual_index + 3..]);
+    real_location
+}
+
+/// Load the Plug'n'Play data from `.pnp.data.json` or from the string literal
+/// `.pnp.cjs` inlines it as.
+fn load_pnp_data(path: &Path) -> eyre::Result<PnpData> {
+    let content = fs::read_to_string(path)?;
+
+    if path.extension().and_then(|extension| extension.to_str()) == Some("json") {
+        return Ok(serde_json::from_str(&content)?);
+    }
+
+    let state_pattern =
+        Regex::new(r"(?:RAW_RUNTIME_STATE\s*=|JSON\.parse\()\s*'((?:[^'\\]|\\(?s:.))*)'").unwrap();
+
+    let state = state_pattern
+        .captures(&content)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| eyre!("Failed to find the runtime state in {:?}", path))?;
+
+    serde_json::from_str(&unescape_string(state.as_str()))
+        .wrap_err_with(|| format!("Failed to parse the runtime state in {:?}", path))
+}
+
+/// Unescape the content of a single quoted string literal, dropping line continuations.
+fn unescape_string(literal: &str) -> String {
+    let mut string = String::with_capacity(literal.len());
+    let mut characters = literal.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next() {
+                Some('\n') | None => {}
+                Some(character) => string.push(character),
+            },
+            _ => string.push(character),
+        }
+    }
+
+    string
+}
+
+/// Load the package json of a package inside a zip archive of the Yarn cache,
+/// e.g. `.yarn/cache/lodash-npm-4.17.21-6382451519-eb835a2e51.zip/node_modules/lodash`.
+///
+/// Returns `None` for packages which are not archived.
+fn load_archived_module(location: &Path) -> Option<eyre::Result<Package>> {
+    let archive_path = location.ancestors().find(|ancestor| {
+        ancestor
+            .extension()
+            .and_then(|extension| extension.to_str())
+            == Some("zip")
+    })?;
+
+    let entry = location
+        .strip_prefix(archive_path)
+        .ok()?
+        .join("package.json")
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    let load = || -> eyre::Result<Package> {
+        let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+        let mut content = Vec::new();
+        archive.by_name(&entry)?.read_to_end(&mut content)?;
+
+        Package::from_slice(&content)
+    };
+
+    Some(load().wrap_err_with(|| format!("Failed to read {entry} from {:?}", archive_path)))
+}
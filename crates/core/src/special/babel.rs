@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+use serde_json::Value;
+
+use crate::package::Package;
+use crate::special::get_item_name;
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::load_config::load_config;
+
+const CONFIG_FILES: [&str; 9] = [
+    ".babelrc",
+    ".babelrc.json",
+    ".babelrc.js",
+    ".babelrc.cjs",
+    ".babelrc.mjs",
+    "babel.config.json",
+    "babel.config.js",
+    "babel.config.cjs",
+    "babel.config.mjs",
+];
+
+/// Get presets and plugins referenced by Babel configurations.
+pub fn check(directory: &Path, package: &Package) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let mut results: Vec<_> = CONFIG_FILES
+        .iter()
+        .filter_map(|file| {
+            let config = load_config(&directory.join(file))?;
+            Some((RelativePathBuf::from(*file), get_dependencies(&config)))
+        })
+        .collect();
+
+    if let Some(config) = &package.babel {
+        results.push((
+            RelativePathBuf::from("package.json"),
+            get_dependencies(config),
+        ));
+    }
+
+    results
+}
+
+fn get_dependencies(config: &Value) -> HashSet<String> {
+    let mut dependencies = HashSet::new();
+    collect_dependencies(config, &mut dependencies);
+    dependencies
+}
+
+fn collect_dependencies(config: &Value, dependencies: &mut HashSet<String>) {
+    let config = match config {
+        Value::Object(config) => config,
+        _ => return,
+    };
+
+    for (key, kind) in [("presets", "preset"), ("plugins", "plugin")] {
+        if let Some(Value::Array(items)) = config.get(key) {
+            dependencies.extend(
+                items
+                    .iter()
+                    .filter_map(get_item_name)
+                    .filter_map(|name| normalize_package_name(name, kind))
+                    .filter_map(|name| extract_package_name(&name)),
+            );
+        }
+    }
+
+    if let Some(Value::Object(envs)) = config.get("env") {
+        for config in envs.values() {
+            collect_dependencies(config, dependencies);
+        }
+    }
+
+    if let Some(Value::Array(overrides)) = config.get("overrides") {
+        for config in overrides {
+            collect_dependencies(config, dependencies);
+        }
+    }
+}
+
+/// Expand a shorthand preset or plugin name into a package name the way Babel does:
+/// `env` becomes `babel-preset-env`, `@babel/env` becomes `@babel/preset-env`,
+/// `@foo/bar` becomes `@foo/babel-preset-bar` and `module:foo` stays `foo`.
+fn normalize_package_name(name: &str, kind: &str) -> Option<String> {
+    if name.starts_with('.') || name.starts_with('/') {
+        return None;
+    }
+
+    if let Some(name) = name.strip_prefix("module:") {
+        return Some(name.to_owned());
+    }
+
+    let prefix = format!("babel-{kind}");
+
+    let name = match name.strip_prefix('@') {
+        Some(scoped_name) => match scoped_name.split_once('/') {
+            None => format!("@{scoped_name}/{prefix}"),
+            Some(("babel", name))
+                if name.starts_with(&format!("{kind}-")) || name.contains('/') =>
+            {
+                format!("@babel/{name}")
+            }
+            Some(("babel", name)) => format!("@babel/{kind}-{name}"),
+            Some((scope, name)) if name.contains(&prefix) || name.contains('/') => {
+                format!("@{scope}/{name}")
+            }
+            Some((scope, name)) => format!("@{scope}/{prefix}-{name}"),
+        },
+        None if name.starts_with(&format!("{prefix}-")) || name.contains('/') => name.to_owned(),
+        None => format!("{prefix}-{name}"),
+    };
+
+    Some(name)
+}
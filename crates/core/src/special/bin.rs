@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use relative_path::RelativePathBuf;
+
+use crate::package::{Bin, Package};
+use crate::resolver::Resolver;
+use crate::special::split_script;
+
+/// Binaries running the command passed after them.
+const WRAPPERS: [&str; 3] = ["cross-env", "cross-env-shell", "env"];
+/// Binaries running a binary of an installed package.
+const RUNNERS: [&str; 3] = ["npx", "pnpx", "bunx"];
+/// Package managers running a script or a binary with `run` or `exec`.
+const PACKAGE_MANAGERS: [&str; 3] = ["npm", "yarn", "pnpm"];
+
+/// Get packages providing the binaries run by `scripts` of a package.
+///
+/// Dependencies are reported for each script, with the script name as the file.
+pub fn check(package: &Package, resolver: &Resolver) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let bin_packages = get_bin_packages(package, resolver);
+
+    package
+        .scripts
+        .iter()
+        .map(|(name, script)| {
+            let dependencies = get_script_binaries(script, package)
+                .into_iter()
+                .filter_map(|binary| match bin_packages.get(&binary) {
+                    Some(dependency) => Some(dependency.to_owned()),
+                    None if package.is_any_dependency(&binary) => Some(binary),
+                    None => None,
+                })
+                .collect();
+
+            (RelativePathBuf::from(name.as_str()), dependencies)
+        })
+        .collect()
+}
+
+/// Map binaries to the installed dependencies declaring them in their `bin` field.
+fn get_bin_packages(package: &Package, resolver: &Resolver) -> HashMap<String, String> {
+    let mut bin_packages = HashMap::new();
+
+    for dependency in package.get_dependency_names() {
+        let bin = match resolver.load_module(dependency) {
+            Ok(Package { bin: Some(bin), .. }) => bin,
+            _ => continue,
+        };
+
+        match bin {
+            // A single binary is named after the package without its scope.
+            Bin::Single(_) => {
+                let name = dependency.rsplit('/').next().unwrap_or(dependency);
+                bin_packages.insert(name.to_owned(), dependency.to_owned());
+            }
+            Bin::Multiple(binaries) => {
+                for binary in binaries.into_keys() {
+                    bin_packages.insert(binary, dependency.to_owned());
+                }
+            }
+        }
+    }
+
+    bin_packages
+}
+
+/// Get binaries run by a script, e.g. `rimraf`, `cross-env` and `jest`
+/// from `rimraf dist && cross-env NODE_ENV=test jest`.
+fn get_script_binaries(script: &str, package: &Package) -> Vec<String> {
+    split_script(script)
+        .iter()
+        .flat_map(|command| get_command_binaries(command, package))
+        .map(str::to_owned)
+        .collect()
+}
+
+fn get_command_binaries<'a>(command: &'a [String], package: &Package) -> Vec<&'a str> {
+    static ENV_PATTERN: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*=").unwrap());
+
+    let mut binaries = Vec::new();
+    let mut tokens = command
+        .iter()
+        .map(String::as_str)
+        .filter(|token| !token.is_empty() && !ENV_PATTERN.is_match(token));
+
+    while let Some(token) = tokens.next() {
+        if WRAPPERS.contains(&token) {
+            binaries.push(token);
+            continue;
+        }
+
+        if RUNNERS.contains(&token) {
+            binaries.extend(skip_options(&mut tokens).and_then(get_binary_name));
+            break;
+        }
+
+        if PACKAGE_MANAGERS.contains(&token) {
+            let mut command = skip_options(&mut tokens);
+
+            if let Some("run" | "run-script" | "exec") = command {
+                command = skip_options(&mut tokens);
+            } else if token == "npm" {
+                // npm only runs binaries with `run` and `exec`.
+                break;
+            }
+
+            binaries.extend(
+                command
+                    .filter(|command| !package.scripts.contains_key(*command))
+                    .and_then(get_binary_name),
+            );
+            break;
+        }
+
+        binaries.extend(get_binary_name(token));
+        break;
+    }
+
+    binaries
+}
+
+/// Get the next token which is not an option, skipping the values of `-p` and `--package`.
+fn skip_options<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    while let Some(token) = tokens.next() {
+        match token {
+            "-p" | "--package" => {
+                tokens.next();
+            }
+            _ if token.starts_with('-') => {}
+            _ => return Some(token),
+        }
+    }
+
+    None
+}
+
+/// Get a binary name from a command, e.g. `tsc` from `./node_modules/.bin/tsc`.
+fn get_binary_name(command: &str) -> Option<&str> {
+    match command.rsplit_once("node_modules/.bin/") {
+        Some((_, binary)) => Some(binary),
+        None if command.starts_with('.') || command.starts_with('/') => None,
+        None => Some(command),
+    }
+}
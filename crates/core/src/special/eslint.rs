@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use relative_path::RelativePathBuf;
+use serde_json::Value;
+
+use crate::package::Package;
+use crate::special::{get_script_options, get_strings};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::load_config::load_config;
+
+const CONFIG_FILES: [&str; 9] = [
+    ".eslintrc",
+    ".eslintrc.json",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+];
+
+/// Get shareable configs, plugins and parsers referenced by ESLint configurations.
+///
+/// Flat configs (`eslint.config.js`) import their plugins and shareable configs,
+/// which the source analysis already finds, so `extends` is only resolved for eslintrc configs.
+pub fn check(directory: &Path, package: &Package) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let config_files = CONFIG_FILES
+        .iter()
+        .map(|file| RelativePathBuf::from(*file))
+        .chain(
+            get_script_options(package, "eslint", &["--config", "-c"])
+                .into_iter()
+                .map(|file| RelativePathBuf::from(file).normalize()),
+        );
+
+    let mut results: Vec<_> = config_files
+        .filter_map(|file| {
+            let path = file.to_path(directory);
+            let config = load_config(&path)?;
+            let dependencies = get_dependencies(&config, path.parent()?);
+
+            Some((file, dependencies))
+        })
+        .collect();
+
+    if let Some(config) = &package.eslint_config {
+        results.push((
+            RelativePathBuf::from("package.json"),
+            get_dependencies(config, directory),
+        ));
+    }
+
+    results
+}
+
+fn get_dependencies(config: &Value, directory: &Path) -> HashSet<String> {
+    let mut dependencies = HashSet::new();
+    let mut visited = HashSet::new();
+
+    match config {
+        Value::Array(configs) => {
+            for config in configs {
+                collect_dependencies(config, directory, false, &mut dependencies, &mut visited);
+            }
+        }
+        _ => collect_dependencies(config, directory, true, &mut dependencies, &mut visited),
+    }
+
+    dependencies
+}
+
+fn collect_dependencies(
+    config: &Value,
+    directory: &Path,
+    is_eslintrc: bool,
+    dependencies: &mut HashSet<String>,
+    visited: &mut HashSet<PathBuf>,
+) {
+    let config = match config {
+        Value::Object(config) => config,
+        _ => return,
+    };
+
+    if is_eslintrc {
+        for extend in get_strings(config.get("extends")) {
+            if extend.starts_with('.') || extend.starts_with('/') {
+                let path = directory.join(extend);
+
+                if !visited.insert(path.clone()) {
+                    continue;
+                }
+
+                if let Some(extended_config) = load_config(&path) {
+                    let directory = path.parent().unwrap_or(directory);
+                    collect_dependencies(&extended_config, directory, true, dependencies, visited);
+                }
+            } else {
+                dependencies.extend(get_extend_dependency(extend));
+            }
+        }
+
+        if let Some(Value::Array(plugins)) = config.get("plugins") {
+            dependencies.extend(
+                plugins
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|plugin| {
+                        extract_package_name(&normalize_package_name(plugin, "eslint-plugin"))
+                    }),
+            );
+        }
+    }
+
+    let parsers = [
+        config.get("parser"),
+        config
+            .get("parserOptions")
+            .and_then(|parser_options| parser_options.get("parser")),
+    ];
+
+    dependencies.extend(
+        parsers
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter(|parser| !parser.starts_with('.') && !parser.starts_with('/'))
+            .filter_map(extract_package_name),
+    );
+
+    if let Some(Value::Array(overrides)) = config.get("overrides") {
+        for config in overrides {
+            collect_dependencies(config, directory, is_eslintrc, dependencies, visited);
+        }
+    }
+}
+
+/// Get a package from an `extends` entry, e.g. `eslint-plugin-react` from
+/// `plugin:react/recommended` or `eslint-config-airbnb` from `airbnb/hooks`.
+fn get_extend_dependency(extend: &str) -> Option<String> {
+    if extend.starts_with("eslint:") {
+        return None;
+    }
+
+    match extend.strip_prefix("plugin:") {
+        Some(plugin) => {
+            let (plugin, _) = plugin.rsplit_once('/')?;
+            extract_package_name(&normalize_package_name(plugin, "eslint-plugin"))
+        }
+        None => extract_package_name(&normalize_package_name(extend, "eslint-config")),
+    }
+}
+
+/// Expand a shorthand config or plugin name into a package name the way ESLint does:
+/// `airbnb` becomes `eslint-config-airbnb`, `@foo` becomes `@foo/eslint-config`
+/// and `@foo/bar` becomes `@foo/eslint-config-bar`.
+fn normalize_package_name(name: &str, prefix: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(scoped_name) => match scoped_name.split_once('/') {
+            None => format!("@{scoped_name}/{prefix}"),
+            Some((_, name)) if name.starts_with(prefix) => format!("@{scoped_name}"),
+            Some((scope, name)) => format!("@{scope}/{prefix}-{name}"),
+        },
+        None if name.starts_with(&format!("{prefix}-")) => name.to_owned(),
+        None => format!("{prefix}-{name}"),
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+use serde_json::Value;
+
+use crate::package::Package;
+use crate::resolver::Resolver;
+use crate::special::{get_item_name, get_strings};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::is_core_module::parse_node_version;
+use crate::util::load_config::load_config;
+
+const CONFIG_FILES: [&str; 5] = [
+    "jest.config.js",
+    "jest.config.cjs",
+    "jest.config.mjs",
+    "jest.config.ts",
+    "jest.config.json",
+];
+
+/// Packages of environments Jest users refer to by name, which aren't always prefixed.
+const KNOWN_ENVIRONMENTS: [(&str, &str); 4] = [
+    ("edge-runtime", "@edge-runtime/jest-environment"),
+    ("happy-dom", "@happy-dom/jest-environment"),
+    ("jsdom", "jest-environment-jsdom"),
+    ("node", "jest-environment-node"),
+];
+
+/// The first Jest major version without the jsdom environment built in.
+const JSDOM_UNBUNDLED_VERSION: u32 = 28;
+
+/// Reporters shipped with Jest.
+const BUILTIN_REPORTERS: [&str; 3] = ["default", "summary", "github-actions"];
+
+/// Get presets, transformers, reporters, environments and other modules
+/// referenced by Jest configurations.
+///
+/// Built-in environments depend on the version of Jest, which is looked up
+/// in the manifest of the installed `jest` package.
+pub fn check(
+    directory: &Path,
+    package: &Package,
+    resolver: &Resolver,
+) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let jest_version = get_jest_major_version(package, resolver);
+
+    let mut results: Vec<_> = CONFIG_FILES
+        .iter()
+        .filter_map(|file| {
+            let config = load_config(&directory.join(file))?;
+            Some((
+                RelativePathBuf::from(*file),
+                get_dependencies(&config, package, jest_version),
+            ))
+        })
+        .collect();
+
+    if let Some(config) = &package.jest {
+        results.push((
+            RelativePathBuf::from("package.json"),
+            get_dependencies(config, package, jest_version),
+        ));
+    }
+
+    results
+}
+
+/// Get the major version of the installed Jest, or else the lowest one the package declares.
+fn get_jest_major_version(package: &Package, resolver: &Resolver) -> Option<u32> {
+    let installed_version = resolver.load_module("jest").ok().map(|jest| jest.version);
+    let declared_version = [
+        &package.dependencies,
+        &package.dev_dependencies,
+        &package.peer_dependencies,
+        &package.optional_dependencies,
+    ]
+    .iter()
+    .find_map(|dependencies| dependencies.get("jest"));
+
+    installed_version
+        .as_deref()
+        .and_then(parse_node_version)
+        .or_else(|| {
+            declared_version
+                .map(String::as_str)
+                .and_then(parse_node_version)
+        })
+        .map(|(major, _)| major)
+}
+
+fn get_dependencies(
+    config: &Value,
+    package: &Package,
+    jest_version: Option<u32>,
+) -> HashSet<String> {
+    let mut dependencies = HashSet::new();
+    collect_dependencies(config, package, jest_version, &mut dependencies);
+    dependencies
+}
+
+fn collect_dependencies(
+    config: &Value,
+    package: &Package,
+    jest_version: Option<u32>,
+    dependencies: &mut HashSet<String>,
+) {
+    let config = match config {
+        Value::Object(config) => config,
+        _ => return,
+    };
+
+    let modules = [
+        "preset",
+        "setupFiles",
+        "setupFilesAfterEnv",
+        "snapshotSerializers",
+    ]
+    .iter()
+    .flat_map(|key| get_strings(config.get(*key)));
+    dependencies.extend(modules.filter_map(get_module_dependency));
+
+    if let Some(Value::Object(transform)) = config.get("transform") {
+        dependencies.extend(
+            transform
+                .values()
+                .filter_map(get_item_name)
+                .filter_map(get_module_dependency),
+        );
+    }
+
+    if let Some(Value::Array(reporters)) = config.get("reporters") {
+        dependencies.extend(
+            reporters
+                .iter()
+                .filter_map(get_item_name)
+                .filter(|reporter| !BUILTIN_REPORTERS.contains(reporter))
+                .filter_map(get_module_dependency),
+        );
+    }
+
+    if let Some(environment) = config.get("testEnvironment").and_then(Value::as_str) {
+        // The node environment is shipped with Jest, and so was jsdom before Jest 28.
+        let is_builtin = match environment {
+            "node" => true,
+            "jsdom" => jest_version.map_or(true, |major| major < JSDOM_UNBUNDLED_VERSION),
+            _ => false,
+        };
+        let environment_package = format!("jest-environment-{environment}");
+
+        if !is_builtin || package.is_any_dependency(&environment_package) {
+            dependencies.extend(resolve_prefixed_module(
+                environment,
+                "jest-environment",
+                package,
+            ));
+        }
+    }
+
+    if let Some(Value::Array(watch_plugins)) = config.get("watchPlugins") {
+        dependencies.extend(
+            watch_plugins
+                .iter()
+                .filter_map(get_item_name)
+                .filter_map(|plugin| resolve_prefixed_module(plugin, "jest-watch", package)),
+        );
+    }
+
+    if let Some(Value::Array(projects)) = config.get("projects") {
+        for project in projects {
+            collect_dependencies(project, package, jest_version, dependencies);
+        }
+    }
+}
+
+/// Get a package from a module path, skipping paths relative to the project.
+fn get_module_dependency(module: &str) -> Option<String> {
+    if module.starts_with('.') || module.starts_with('/') || module.starts_with("<rootDir>") {
+        return None;
+    }
+
+    extract_package_name(module)
+}
+
+/// Resolve a module the way Jest does for environments and watch plugins,
+/// which are looked up with a prefix first, e.g. `jest-environment-jsdom` for `jsdom`.
+/// The prefixed package is only used when it is declared, or for known environments.
+fn resolve_prefixed_module(module: &str, prefix: &str, package: &Package) -> Option<String> {
+    let dependency = get_module_dependency(module)?;
+
+    if dependency.starts_with(prefix) || dependency.contains('/') {
+        return Some(dependency);
+    }
+
+    let prefixed_dependency = format!("{prefix}-{dependency}");
+    if package.is_any_dependency(&prefixed_dependency) {
+        return Some(prefixed_dependency);
+    }
+
+    let known_environment = KNOWN_ENVIRONMENTS
+        .iter()
+        .find(|(environment, _)| prefix == "jest-environment" && *environment == dependency);
+
+    Some(
+        known_environment.map_or(dependency, |(_, environment_package)| {
+            environment_package.to_string()
+        }),
+    )
+}
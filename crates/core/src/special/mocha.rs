@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::fs;
+use std::iter;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+use serde_json::{Map, Value};
+
+use crate::package::Package;
+use crate::special::{get_script_options, get_strings};
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::load_config::load_config;
+
+const CONFIG_FILES: [&str; 6] = [
+    ".mocharc.js",
+    ".mocharc.cjs",
+    ".mocharc.json",
+    ".mocharc.jsonc",
+    ".mocharc.yaml",
+    ".mocharc.yml",
+];
+
+const OPTS_FILE: &str = "test/mocha.opts";
+
+/// Reporters shipped with Mocha.
+const BUILTIN_REPORTERS: [&str; 15] = [
+    "base",
+    "doc",
+    "dot",
+    "html",
+    "json",
+    "json-stream",
+    "landing",
+    "list",
+    "markdown",
+    "min",
+    "nyan",
+    "progress",
+    "spec",
+    "tap",
+    "xunit",
+];
+
+/// Interfaces shipped with Mocha.
+const BUILTIN_INTERFACES: [&str; 4] = ["bdd", "tdd", "qunit", "exports"];
+
+/// Get required modules, reporters, interfaces and specs referenced by Mocha configurations,
+/// including legacy `mocha.opts` files.
+pub fn check(directory: &Path, package: &Package) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let config_files = CONFIG_FILES
+        .iter()
+        .map(|file| RelativePathBuf::from(*file))
+        .chain(get_script_files(package, "--config"));
+
+    let configs = config_files.filter_map(|file| {
+        let config = load_config(&file.to_path(directory))?;
+        Some((file, config))
+    });
+
+    let opts_files =
+        iter::once(RelativePathBuf::from(OPTS_FILE)).chain(get_script_files(package, "--opts"));
+
+    let opts = opts_files.filter_map(|file| {
+        let content = fs::read_to_string(file.to_path(directory)).ok()?;
+        Some((file, parse_opts(&content)))
+    });
+
+    let package_config = package
+        .mocha
+        .iter()
+        .map(|config| (RelativePathBuf::from("package.json"), config.to_owned()));
+
+    configs
+        .chain(opts)
+        .chain(package_config)
+        .map(|(file, config)| (file, get_dependencies(&config)))
+        .collect()
+}
+
+/// Get configuration files passed with an option to `mocha` in package scripts.
+fn get_script_files(package: &Package, option: &str) -> Vec<RelativePathBuf> {
+    get_script_options(package, "mocha", &[option])
+        .into_iter()
+        .map(|file| RelativePathBuf::from(file).normalize())
+        .collect()
+}
+
+/// Parse command line options of a `mocha.opts` file into a configuration,
+/// e.g. `{ "require": ["babel"] }` from `--require babel`.
+fn parse_opts(content: &str) -> Value {
+    let mut config = Map::new();
+    let mut tokens = content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(str::split_whitespace)
+        .peekable();
+
+    while let Some(token) = tokens.next() {
+        let option = token.trim_start_matches('-');
+        if option == token {
+            continue;
+        }
+
+        let (option, value) = match option.split_once('=') {
+            Some((option, value)) => (option, Some(value)),
+            None => (option, tokens.next_if(|value| !value.starts_with('-'))),
+        };
+
+        if let Some(value) = value {
+            let values = config
+                .entry(option)
+                .or_insert_with(|| Value::Array(Vec::new()));
+
+            if let Value::Array(values) = values {
+                values.push(Value::String(value.to_owned()));
+            }
+        }
+    }
+
+    Value::Object(config)
+}
+
+fn get_dependencies(config: &Value) -> HashSet<String> {
+    let get_option = |names: &[&str]| -> Vec<&str> {
+        names
+            .iter()
+            .flat_map(|name| get_strings(config.get(*name)))
+            .collect()
+    };
+
+    let requires = get_option(&["require", "r"])
+        .into_iter()
+        .filter(|module| !module.starts_with('.') && !module.starts_with('/'));
+
+    let reporters = get_option(&["reporter", "R"])
+        .into_iter()
+        .filter(|reporter| !BUILTIN_REPORTERS.contains(reporter))
+        .filter(|reporter| !reporter.starts_with('.') && !reporter.starts_with('/'));
+
+    let interfaces = get_option(&["ui", "u"])
+        .into_iter()
+        .filter(|interface| !BUILTIN_INTERFACES.contains(interface))
+        .filter(|interface| !interface.starts_with('.') && !interface.starts_with('/'));
+
+    // Specs are paths, so only the ones pointing into node_modules reference packages.
+    let specs = get_option(&["spec"])
+        .into_iter()
+        .filter_map(|spec| spec.strip_prefix("node_modules/"));
+
+    requires
+        .chain(reporters)
+        .chain(interfaces)
+        .chain(specs)
+        .filter_map(extract_package_name)
+        .collect()
+}
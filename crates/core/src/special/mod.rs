@@ -0,0 +1,140 @@
+pub(crate) mod babel;
+pub(crate) mod bin;
+pub(crate) mod eslint;
+pub(crate) mod jest;
+pub(crate) mod mocha;
+pub(crate) mod typescript;
+pub(crate) mod webpack;
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+use serde_json::Value;
+
+use crate::package::Package;
+use crate::resolver::Resolver;
+
+/// A detector of dependencies referenced by tool configurations rather than imported by sources.
+/// Returns dependencies for each configuration file they are referenced from.
+type Special = fn(&Path, &Package) -> Vec<(RelativePathBuf, HashSet<String>)>;
+
+/// Get dependencies referenced by tool configurations of a package.
+///
+/// Binaries run by scripts and the version of Jest are looked up in manifests of dependencies
+/// installed for the package.
+pub fn check_specials(
+    directory: &Path,
+    package: &Package,
+    resolver: &Resolver,
+) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let specials: [Special; 5] = [
+        babel::check,
+        eslint::check,
+        mocha::check,
+        typescript::check,
+        webpack::check,
+    ];
+
+    specials
+        .iter()
+        .flat_map(|special| special(directory, package))
+        .chain(jest::check(directory, package, resolver))
+        .chain(bin::check(package, resolver))
+        .collect()
+}
+
+/// Get strings of a configuration value which is either a string or an array of strings.
+pub fn get_strings(value: Option<&Value>) -> Vec<&str> {
+    match value {
+        Some(Value::String(value)) => vec![value.as_str()],
+        Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Get the name of a preset, plugin or reporter entry, which is either a name
+/// or an array of a name followed by options.
+pub fn get_item_name(item: &Value) -> Option<&str> {
+    match item {
+        Value::String(name) => Some(name),
+        Value::Array(item) => item.first()?.as_str(),
+        _ => None,
+    }
+}
+
+/// Get values passed with any of the options to a binary in package scripts,
+/// e.g. `./config.js` for `eslint --config ./config.js`.
+pub fn get_script_options(package: &Package, binary: &str, options: &[&str]) -> Vec<String> {
+    let binary_path = format!("/{binary}");
+
+    package
+        .scripts
+        .values()
+        .flat_map(|script| split_script(script))
+        .flat_map(|command| {
+            let mut values = Vec::new();
+            let mut words = command
+                .iter()
+                .map(String::as_str)
+                .skip_while(|&word| word != binary && !word.ends_with(&binary_path))
+                .skip(1);
+
+            while let Some(word) = words.next() {
+                let value = if options.contains(&word) {
+                    words.next()
+                } else {
+                    word.split_once('=')
+                        .filter(|(option, _)| options.contains(option))
+                        .map(|(_, value)| value)
+                };
+
+                values.extend(value.map(str::to_owned));
+            }
+
+            values
+        })
+        .collect()
+}
+
+/// Split a script into its commands, separated by `&&`, `||`, `;`, `|` or `&`,
+/// and each command into its words without quotes, e.g. `[["rimraf", "dist"], ["jest"]]`
+/// from `rimraf "dist" && jest`.
+pub fn split_script(script: &str) -> Vec<Vec<String>> {
+    let mut commands = Vec::new();
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut characters = script.chars();
+
+    while let Some(character) = characters.next() {
+        match (quote, character) {
+            (Some(opening), _) if character == opening => quote = None,
+            (Some('"') | None, '\\') => {
+                let word = word.get_or_insert_with(String::new);
+                word.extend(characters.next());
+            }
+            (Some(_), _) => word.get_or_insert_with(String::new).push(character),
+            (None, '"' | '\'') => {
+                quote = Some(character);
+                word.get_or_insert_with(String::new);
+            }
+            // Redirections like `2>&1` don't separate commands.
+            (None, '&') if word.as_deref().map_or(false, |word| word.ends_with('>')) => {
+                word.get_or_insert_with(String::new).push(character);
+            }
+            (None, '&' | '|' | ';') => {
+                words.extend(word.take());
+                commands.push(std::mem::take(&mut words));
+            }
+            (None, _) if character.is_whitespace() => words.extend(word.take()),
+            (None, _) => word.get_or_insert_with(String::new).push(character),
+        }
+    }
+
+    words.extend(word.take());
+    commands.push(words);
+    commands.retain(|words| !words.is_empty());
+
+    commands
+}
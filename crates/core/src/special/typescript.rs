@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+
+use crate::package::Package;
+use crate::special::get_script_options;
+use crate::tsconfig::TsConfig;
+use crate::util::extract_package_name::extract_package_name;
+use crate::util::extract_type_name::extract_type_name;
+
+const CONFIG_FILES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
+/// The type root used when `typeRoots` is not set.
+const DEFAULT_TYPE_ROOT: &str = "./node_modules/@types";
+
+/// Get base configurations, type packages and language service plugins
+/// referenced by TypeScript configurations.
+pub fn check(directory: &Path, package: &Package) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    let projects = get_script_options(package, "tsc", &["--project", "-p"])
+        .into_iter()
+        .map(|project| {
+            let project = RelativePathBuf::from(project).normalize();
+            if project.to_path(directory).is_dir() {
+                project.join("tsconfig.json")
+            } else {
+                project
+            }
+        });
+
+    let config_files = CONFIG_FILES
+        .iter()
+        .map(|file| RelativePathBuf::from(*file))
+        .chain(projects)
+        .collect::<HashSet<_>>();
+
+    config_files
+        .into_iter()
+        .filter_map(|file| {
+            let config = TsConfig::resolve(file.to_path(directory))
+                .map_err(|error| {
+                    log::debug!("failed to load tsconfig {:#?}", error);
+                })
+                .ok()?;
+
+            Some((file, get_dependencies(&config, package)))
+        })
+        .collect()
+}
+
+fn get_dependencies(config: &TsConfig, package: &Package) -> HashSet<String> {
+    let mut dependencies = config.extended_packages.clone();
+    let compiler_options = &config.compiler_options;
+
+    if let Some(plugins) = &compiler_options.plugins {
+        dependencies.extend(
+            plugins
+                .iter()
+                .filter(|plugin| !plugin.name.starts_with('.') && !plugin.name.starts_with('/'))
+                .filter_map(|plugin| extract_package_name(&plugin.name)),
+        );
+    }
+
+    let type_roots = match &compiler_options.type_roots {
+        Some(type_roots) => type_roots.iter().map(String::as_str).collect(),
+        None => vec![DEFAULT_TYPE_ROOT],
+    };
+
+    // Type roots inside node_modules are either a scope of type packages like `@types`
+    // or a single package containing type declarations.
+    let (scopes, type_packages): (Vec<_>, Vec<_>) = type_roots
+        .into_iter()
+        .filter_map(|type_root| type_root.rsplit_once("node_modules/"))
+        .map(|(_, type_root)| type_root.trim_end_matches('/'))
+        .partition(|type_root| type_root.starts_with('@') && !type_root.contains('/'));
+
+    dependencies.extend(type_packages.into_iter().filter_map(extract_package_name));
+
+    match &compiler_options.types {
+        Some(types) => dependencies.extend(
+            types
+                .iter()
+                .filter_map(|type_name| get_type_dependency(type_name, package)),
+        ),
+        // Without `types` every package of the type roots is included.
+        None => dependencies.extend(
+            package
+                .get_dependency_names()
+                .filter(|dependency| {
+                    scopes
+                        .iter()
+                        .any(|scope| dependency.starts_with(&format!("{scope}/")))
+                })
+                .cloned(),
+        ),
+    }
+
+    dependencies
+}
+
+/// Get a package from a `types` entry, which is looked up in the `@types` scope first,
+/// e.g. `@types/node` for `node` and `vite` for `vite/client`.
+fn get_type_dependency(type_name: &str, package: &Package) -> Option<String> {
+    if type_name.starts_with('.') || type_name.starts_with('/') {
+        return None;
+    }
+
+    let dependency = extract_package_name(type_name)?;
+
+    if dependency.starts_with("@types/") {
+        return Some(dependency);
+    }
+
+    let type_dependency = extract_type_name(&dependency);
+    if package.is_any_dependency(&dependency) && !package.is_any_dependency(&type_dependency) {
+        Some(dependency)
+    } else {
+        Some(type_dependency)
+    }
+}
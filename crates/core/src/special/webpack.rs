@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use relative_path::RelativePathBuf;
+use serde_json::Value;
+
+use crate::package::Package;
+use crate::special::get_strings;
+use crate::util::extract_loader_name::extract_loader_name;
+use crate::util::load_config::load_config;
+use crate::util::split_webpack_request::split_webpack_request;
+
+const CONFIG_FILES: [&str; 5] = [
+    "webpack.config.js",
+    "webpack.config.cjs",
+    "webpack.config.mjs",
+    "webpack.config.ts",
+    "webpack.config.babel.js",
+];
+
+const LOADER_KEYS: [&str; 3] = ["loader", "loaders", "use"];
+
+/// Get loaders referenced by webpack configurations.
+pub fn check(directory: &Path, package: &Package) -> Vec<(RelativePathBuf, HashSet<String>)> {
+    CONFIG_FILES
+        .iter()
+        .filter_map(|file| {
+            let config = load_config(&directory.join(file))?;
+
+            let mut dependencies = HashSet::new();
+            collect_dependencies(&config, package, &mut dependencies);
+
+            Some((RelativePathBuf::from(*file), dependencies))
+        })
+        .collect()
+}
+
+/// Collect loaders of `loader`, `loaders` and `use` entries anywhere in a configuration,
+/// which covers nested `oneOf` rules and object entries like `{ loader: "css-loader" }`.
+fn collect_dependencies(config: &Value, package: &Package, dependencies: &mut HashSet<String>) {
+    match config {
+        Value::Object(config) => {
+            for (key, value) in config {
+                if LOADER_KEYS.contains(&key.as_str()) {
+                    dependencies.extend(
+                        get_strings(Some(value))
+                            .into_iter()
+                            .flat_map(split_webpack_request)
+                            .filter_map(|loader| extract_loader_name(loader, package)),
+                    );
+                }
+
+                collect_dependencies(value, package, dependencies);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_dependencies(value, package, dependencies);
+            }
+        }
+        _ => {}
+    }
+}
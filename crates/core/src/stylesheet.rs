@@ -0,0 +1,293 @@
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::util::extract_package_name::extract_package_name;
+
+static SASS_RULE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@(import|use|forward)\s+([^;{\n]+)").unwrap());
+static QUOTED_ARGUMENT_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*(?:"([^"]+)"|'([^']+)')"#).unwrap());
+static CSS_IMPORT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"@import\s+(?:\([^)]*\)\s*)?(?:url\(\s*)?(?:"([^"]+)"|'([^']+)'|([^\s"');]+))"#)
+        .unwrap()
+});
+static URL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"url\(\s*(?:"([^"]+)"|'([^']+)'|([^\s"')]+))"#).unwrap());
+
+/// Get the packages imported by a stylesheet.
+///
+/// Returns `None` for files which are not stylesheets.
+pub fn parse_stylesheet(file: &Path) -> Option<Vec<String>> {
+    let extension = file.extension()?.to_str()?;
+
+    let extract_imports: fn(&str) -> Vec<String> = match extension {
+        "scss" | "sass" => extract_sass_imports,
+        "css" | "less" => extract_css_imports,
+        _ => return None,
+    };
+
+    log::debug!("parse stylesheet {:#?}", file);
+
+    // Only the preprocessors have `//` line comments, plain CSS has block comments.
+    let has_line_comments = extension != "css";
+    let source = strip_comments(&load_source(file)?, has_line_comments);
+    let directory = file.parent()?;
+
+    let imports = extract_imports(&source)
+        .into_iter()
+        .filter_map(|specifier| get_import_dependency(directory, &specifier));
+
+    let urls = extract_urls(&source)
+        .into_iter()
+        .filter_map(|specifier| get_url_dependency(&specifier));
+
+    Some(imports.chain(urls).collect())
+}
+
+fn load_source(file: &Path) -> Option<String> {
+    fs::read_to_string(file)
+        .map_err(|error| {
+            log::error!("failed to load {:#?}", error);
+        })
+        .ok()
+}
+
+/// Remove block comments and, for preprocessors, `//` line comments.
+/// Strings and unquoted `url()` arguments are kept as is, as they may contain `//` or `/*`.
+fn strip_comments(source: &str, has_line_comments: bool) -> String {
+    let bytes = source.as_bytes();
+    let mut stripped = String::with_capacity(source.len());
+    let mut last_end = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match (bytes[index], bytes.get(index + 1)) {
+            (b'/', Some(b'*')) => {
+                stripped.push_str(&source[last_end..index]);
+                index = find_from(source, index + 2, "*/").map_or(bytes.len(), |end| end + 2);
+                last_end = index;
+            }
+            (b'/', Some(b'/')) if has_line_comments => {
+                stripped.push_str(&source[last_end..index]);
+                index = find_from(source, index + 2, "\n").unwrap_or(bytes.len());
+                last_end = index;
+            }
+            (quote @ (b'"' | b'\''), _) => index = skip_string(bytes, index + 1, quote),
+            (b'u' | b'U', _) if is_url_start(bytes, index) => {
+                index = skip_url(bytes, index + "url(".len());
+            }
+            _ => index += 1,
+        }
+    }
+
+    stripped.push_str(&source[last_end..]);
+    stripped
+}
+
+/// Check if a `url(` function starts at an index.
+fn is_url_start(bytes: &[u8], index: usize) -> bool {
+    let is_name_start = index == 0
+        || !matches!(bytes[index - 1], b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_');
+
+    is_name_start
+        && bytes
+            .get(index..index + "url(".len())
+            .map_or(false, |name| name.eq_ignore_ascii_case(b"url("))
+}
+
+/// Get the index after the `)` closing a `url()` argument, skipping quoted urls.
+fn skip_url(bytes: &[u8], start: usize) -> usize {
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b')' => return index + 1,
+            quote @ (b'"' | b'\'') => index = skip_string(bytes, index + 1, quote),
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Get the index after the quote closing a string. Unterminated strings end at the line end.
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 1,
+            b'\n' => return index,
+            byte if byte == quote => return index + 1,
+            _ => {}
+        }
+        index += 1;
+    }
+
+    bytes.len()
+}
+
+fn find_from(source: &str, start: usize, pattern: &str) -> Option<usize> {
+    source
+        .get(start..)?
+        .find(pattern)
+        .map(|index| start + index)
+}
+
+/// Get specifiers of `@import`, `@use` and `@forward` rules.
+fn extract_sass_imports(source: &str) -> Vec<String> {
+    SASS_RULE_PATTERN
+        .captures_iter(source)
+        .flat_map(|captures| {
+            let rule = captures
+                .get(1)
+                .map(|rule| rule.as_str())
+                .unwrap_or_default();
+            let arguments = captures
+                .get(2)
+                .map(|arguments| arguments.as_str())
+                .unwrap_or_default();
+
+            if rule == "import" {
+                arguments
+                    .split(',')
+                    .filter(|argument| !is_plain_css_import(argument))
+                    .filter_map(get_argument_specifier)
+                    .collect()
+            } else {
+                // `@use` and `@forward` take a single url followed by `as`, `with`, `show`, etc.
+                get_argument_specifier(arguments)
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            }
+        })
+        .collect()
+}
+
+/// Get the url of a Sass rule argument, quoted or unquoted as the indented syntax allows.
+fn get_argument_specifier(argument: &str) -> Option<String> {
+    match QUOTED_ARGUMENT_PATTERN.captures(argument) {
+        Some(captures) => captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .map(|specifier| specifier.as_str().to_owned()),
+        None => argument
+            .split_whitespace()
+            .next()
+            .map(|specifier| specifier.to_owned()),
+    }
+}
+
+/// Check if a Sass `@import` argument is a plain CSS import, which Sass leaves to the browser:
+/// a `url()`, a `.css` file, a remote url or an import with media queries.
+fn is_plain_css_import(argument: &str) -> bool {
+    let argument = argument.trim();
+
+    if argument.starts_with("url(") || argument.split_whitespace().count() > 1 {
+        return true;
+    }
+
+    let specifier = argument.trim_matches(|character| character == '"' || character == '\'');
+    specifier.ends_with(".css")
+        || specifier.starts_with("http://")
+        || specifier.starts_with("https://")
+        || specifier.starts_with("//")
+}
+
+/// Get specifiers of CSS and Less `@import` rules, including Less import options
+/// like `@import (reference) "file"`.
+fn extract_css_imports(source: &str) -> Vec<String> {
+    CSS_IMPORT_PATTERN
+        .captures_iter(source)
+        .filter_map(|captures| {
+            captures
+                .get(1)
+                .or_else(|| captures.get(2))
+                .or_else(|| captures.get(3))
+        })
+        .map(|specifier| specifier.as_str().to_owned())
+        .collect()
+}
+
+/// Get specifiers of `url()` references.
+fn extract_urls(source: &str) -> Vec<String> {
+    URL_PATTERN
+        .captures_iter(source)
+        .filter_map(|captures| {
+            captures
+                .get(1)
+                .or_else(|| captures.get(2))
+                .or_else(|| captures.get(3))
+        })
+        .map(|specifier| specifier.as_str().to_owned())
+        .collect()
+}
+
+/// Get a package from a stylesheet import, skipping remote urls, relative paths and partials.
+fn get_import_dependency(directory: &Path, specifier: &str) -> Option<String> {
+    // Built-in Sass modules, like `sass:math`, come with any compiler,
+    // be it `sass`, `sass-embedded` or `node-sass`.
+    if specifier.starts_with("sass:")
+        || specifier.starts_with("url(")
+        || specifier.starts_with("http://")
+        || specifier.starts_with("https://")
+        || specifier.starts_with("//")
+    {
+        return None;
+    }
+
+    // Webpack resolves `~` prefixed imports from node_modules.
+    if let Some(specifier) = specifier.strip_prefix('~') {
+        return extract_package_name(specifier);
+    }
+
+    if let Some(specifier) = specifier.strip_prefix("node_modules/") {
+        return extract_package_name(specifier);
+    }
+
+    if specifier.starts_with('.') || specifier.starts_with('/') || specifier.starts_with('_') {
+        return None;
+    }
+
+    if is_local_stylesheet(directory, specifier) {
+        return None;
+    }
+
+    extract_package_name(specifier)
+}
+
+/// Get a package from a `url()` reference. Unlike imports, bare urls are relative,
+/// so only `~` and `node_modules/` prefixed ones point into packages.
+fn get_url_dependency(specifier: &str) -> Option<String> {
+    specifier
+        .strip_prefix('~')
+        .or_else(|| specifier.strip_prefix("node_modules/"))
+        .and_then(extract_package_name)
+}
+
+/// Check if an import resolves to a stylesheet next to the importing file.
+fn is_local_stylesheet(directory: &Path, specifier: &str) -> bool {
+    let path = directory.join(specifier);
+
+    if path.is_file() {
+        return true;
+    }
+
+    let file_name = match path.file_name().and_then(|file_name| file_name.to_str()) {
+        Some(file_name) => file_name.to_owned(),
+        None => return false,
+    };
+
+    ["scss", "sass", "less", "css"].iter().any(|extension| {
+        path.with_file_name(format!("{file_name}.{extension}"))
+            .is_file()
+            || path
+                .with_file_name(format!("_{file_name}.{extension}"))
+                .is_file()
+            || path.join(format!("index.{extension}")).is_file()
+            || path.join(format!("_index.{extension}")).is_file()
+    })
+}
@@ -0,0 +1,289 @@
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::util::extract_package_name::extract_package_name;
+
+/// Files a TypeScript or JavaScript project is configured with.
+const PROJECT_FILES: [&str; 2] = ["tsconfig.json", "jsconfig.json"];
+
+/// Extensions of modules `baseUrl` imports may resolve to.
+const MODULE_EXTENSIONS: [&str; 10] = [
+    "ts", "tsx", "d.ts", "js", "jsx", "mjs", "cjs", "json", "vue", "svelte",
+];
+
+/// An `extends` entry, either a single base configuration or a list of them.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Extends {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+/// A language service plugin.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Plugin {
+    /// The plugin module.
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    /// The optional list of type packages to include.
+    #[serde(default)]
+    pub types: Option<Vec<String>>,
+    /// The optional list of directories to include type packages from.
+    #[serde(default)]
+    pub type_roots: Option<Vec<String>>,
+    /// The optional list of language service plugins.
+    #[serde(default)]
+    pub plugins: Option<Vec<Plugin>>,
+    /// The optional directory to resolve non-relative module names from.
+    #[serde(default)]
+    pub base_url: Option<PathBuf>,
+    /// The optional map of module name patterns to locations.
+    #[serde(default)]
+    pub paths: Option<BTreeMap<String, Vec<String>>>,
+    /// The directory of the configuration declaring `paths`, which targets are relative to
+    /// without `baseUrl`.
+    #[serde(skip)]
+    pub paths_directory: Option<PathBuf>,
+}
+
+impl CompilerOptions {
+    /// Fill options missing from these options with the options of a base configuration.
+    fn inherit(self, base: CompilerOptions) -> Self {
+        let (paths, paths_directory) = match self.paths {
+            Some(paths) => (Some(paths), self.paths_directory),
+            None => (base.paths, base.paths_directory),
+        };
+
+        CompilerOptions {
+            types: self.types.or(base.types),
+            type_roots: self.type_roots.or(base.type_roots),
+            plugins: self.plugins.or(base.plugins),
+            base_url: self.base_url.or(base.base_url),
+            paths,
+            paths_directory,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TsConfig {
+    /// The optional base configurations.
+    #[serde(default)]
+    pub extends: Option<Extends>,
+    /// The compiler options.
+    #[serde(default)]
+    pub compiler_options: CompilerOptions,
+    /// Packages of base configurations extended by configurations outside of node_modules.
+    #[serde(skip)]
+    pub extended_packages: HashSet<String>,
+    #[serde(skip)]
+    local_specifiers: LocalSpecifierCache,
+}
+
+/// Whether specifiers point to project files, shared by clones of a configuration,
+/// so every specifier is only looked up on disk once.
+#[derive(Clone, Debug, Default)]
+struct LocalSpecifierCache(Arc<Mutex<HashMap<String, bool>>>);
+
+impl PartialEq for LocalSpecifierCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LocalSpecifierCache {}
+
+impl TsConfig {
+    /// Deserializes a `TsConfig` from a file path, allowing comments and trailing commas.
+    pub fn from_path<T: AsRef<Path>>(path: T) -> eyre::Result<Self> {
+        let content = fs::read_to_string(path.as_ref())?;
+        Ok(json5::from_str(&content)?)
+    }
+
+    /// Deserializes a `TsConfig` from a file path and inherits compiler options
+    /// of its base configurations, following `extends` chains into node_modules.
+    pub fn resolve<T: AsRef<Path>>(path: T) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let mut visited = HashSet::from([path.to_path_buf()]);
+        Self::resolve_with_visited(path, &mut visited)
+    }
+
+    fn resolve_with_visited(path: &Path, visited: &mut HashSet<PathBuf>) -> eyre::Result<Self> {
+        let mut config = Self::from_path(path)?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        // `baseUrl` is relative to the configuration declaring it.
+        config.compiler_options.base_url = config
+            .compiler_options
+            .base_url
+            .map(|base_url| directory.join(base_url));
+        if config.compiler_options.paths.is_some() {
+            config.compiler_options.paths_directory = Some(directory.to_path_buf());
+        }
+        let is_project_file = !is_in_node_modules(path);
+
+        let mut base_options = CompilerOptions::default();
+        let mut extended_packages = HashSet::new();
+
+        for specifier in config.get_extends() {
+            let is_package = !specifier.starts_with('.') && !Path::new(specifier).is_absolute();
+
+            if is_package && is_project_file {
+                extended_packages.extend(extract_package_name(specifier));
+            }
+
+            let base_path = match resolve_extends(directory, specifier, is_package) {
+                Some(base_path) if visited.insert(base_path.clone()) => base_path,
+                _ => continue,
+            };
+
+            match Self::resolve_with_visited(&base_path, visited) {
+                Ok(base) => {
+                    base_options = base.compiler_options.inherit(base_options);
+                    extended_packages.extend(base.extended_packages);
+                }
+                Err(error) => {
+                    log::error!("failed to load {:#?}: {:#?}", base_path, error);
+                }
+            }
+        }
+
+        config.compiler_options = config.compiler_options.inherit(base_options);
+        config.extended_packages = extended_packages;
+
+        Ok(config)
+    }
+
+    /// Loads the configuration of a project directory, if it has one.
+    pub fn from_directory(directory: &Path) -> Option<Self> {
+        let path = PROJECT_FILES
+            .iter()
+            .map(|file| directory.join(file))
+            .find(|path| path.is_file())?;
+
+        Self::resolve(&path)
+            .map_err(|error| {
+                log::error!("failed to load {:#?}: {:#?}", path, error);
+            })
+            .ok()
+    }
+
+    /// Check if a module specifier points to project files through `paths` or `baseUrl`,
+    /// e.g. `@app/utils` for `"@app/*": ["src/*"]` with `src/utils.ts`.
+    ///
+    /// Patterns like `"*": ["types/*"]` match every specifier, so a specifier is only local
+    /// when one of the targets of a matching pattern is a project file.
+    pub fn is_local_specifier(&self, specifier: &str) -> bool {
+        if let Some(&is_local) = self.local_specifiers.0.lock().unwrap().get(specifier) {
+            return is_local;
+        }
+
+        let is_local = self.find_local_specifier(specifier);
+
+        self.local_specifiers
+            .0
+            .lock()
+            .unwrap()
+            .insert(specifier.to_owned(), is_local);
+
+        is_local
+    }
+
+    fn find_local_specifier(&self, specifier: &str) -> bool {
+        let compiler_options = &self.compiler_options;
+        let paths_base = compiler_options
+            .base_url
+            .as_ref()
+            .or(compiler_options.paths_directory.as_ref());
+
+        let is_alias = compiler_options
+            .paths
+            .iter()
+            .flatten()
+            .any(|(pattern, targets)| {
+                let matched = match pattern.split_once('*') {
+                    Some((prefix, suffix)) => specifier
+                        .strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_suffix(suffix)),
+                    None => (specifier == pattern).then(|| ""),
+                };
+
+                match (matched, paths_base) {
+                    (Some(matched), Some(paths_base)) => targets.iter().any(|target| {
+                        is_module_file(&paths_base.join(target.replacen('*', matched, 1)))
+                    }),
+                    _ => false,
+                }
+            });
+
+        is_alias
+            || compiler_options
+                .base_url
+                .as_ref()
+                .map(|base_url| is_module_file(&base_url.join(specifier)))
+                .unwrap_or(false)
+    }
+
+    pub fn get_extends(&self) -> Vec<&str> {
+        match &self.extends {
+            Some(Extends::Single(extends)) => vec![extends.as_str()],
+            Some(Extends::Multiple(extends)) => extends.iter().map(String::as_str).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Get the file of a base configuration, which is either a path or a module
+/// looked up in node_modules of the directory and its ancestors.
+fn resolve_extends(directory: &Path, specifier: &str, is_package: bool) -> Option<PathBuf> {
+    let bases: Vec<PathBuf> = if is_package {
+        directory
+            .ancestors()
+            .map(|ancestor| ancestor.join("node_modules").join(specifier))
+            .collect()
+    } else {
+        vec![directory.join(specifier)]
+    };
+
+    bases.into_iter().find_map(|base| {
+        let mut with_extension = OsString::from(base.as_os_str());
+        with_extension.push(".json");
+
+        [
+            base.clone(),
+            PathBuf::from(with_extension),
+            base.join("tsconfig.json"),
+        ]
+        .into_iter()
+        .find(|path| path.is_file())
+    })
+}
+
+/// Check if a path is a module file, either as is, with an extension or as an index file.
+fn is_module_file(path: &Path) -> bool {
+    let with_extension = |extension: &str| {
+        let mut path = OsString::from(path.as_os_str());
+        path.push(format!(".{extension}"));
+        PathBuf::from(path)
+    };
+
+    path.is_file()
+        || MODULE_EXTENSIONS.iter().any(|extension| {
+            with_extension(extension).is_file() || path.join(format!("index.{extension}")).is_file()
+        })
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components()
+        .any(|component| component == Component::Normal("node_modules".as_ref()))
+}
@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use eyre::WrapErr;
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+
+use crate::config::Config;
+
+/// Get a walker of a directory which skips the files ignored by the config,
+/// with the ignore patterns relative to the checked directory.
+pub fn build_walker(config: &Config, directory: &Path) -> eyre::Result<WalkBuilder> {
+    let mut override_builder = OverrideBuilder::new(config.get_directory());
+
+    for pattern in config.get_ignore_patterns() {
+        override_builder
+            .add(&format!("!{pattern}"))
+            .wrap_err_with(|| format!("Malformed ignore pattern: {pattern}"))?;
+    }
+
+    let overrides = override_builder
+        .build()
+        .wrap_err_with(|| "Failed to build override builder")?;
+    let mut walker = WalkBuilder::new(directory);
+
+    walker.overrides(overrides);
+
+    if let Some(path) = config.ignore_path() {
+        walker.add_custom_ignore_filename(path);
+    }
+
+    Ok(walker)
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+use swc_ecma_ast::{
+    BlockStmt, BlockStmtOrExpr, CallExpr, Callee, Decl, ExportDecl, Expr, Lit, MemberExpr,
+    MemberProp, Module, ModuleDecl, ModuleItem, Pat, PatOrExpr, Prop, PropName, PropOrSpread, Stmt,
+};
+
+/// Maximum depth of nested expressions and bindings followed while evaluating.
+const MAX_DEPTH: usize = 64;
+
+/// Statically evaluate the value exported by a configuration module with
+/// `module.exports = ...` or `export default ...`.
+///
+/// Only literals, top-level bindings and a few call patterns are evaluated,
+/// everything that needs running the module becomes `null`.
+pub fn evaluate_module(module: &Module) -> Option<Value> {
+    let evaluator = Evaluator::new(module);
+
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => Some(&*export.expr),
+            ModuleItem::Stmt(Stmt::Expr(statement)) => match &*statement.expr {
+                Expr::Assign(assign) if is_module_exports(&assign.left) => Some(&*assign.right),
+                _ => None,
+            },
+            _ => None,
+        })
+        .last()
+        .map(|expr| evaluator.evaluate(expr, 0))
+}
+
+struct Evaluator<'a> {
+    /// Initializers of top-level variables.
+    bindings: HashMap<String, &'a Expr>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(module: &'a Module) -> Self {
+        let mut bindings = HashMap::new();
+
+        for item in &module.body {
+            let declaration = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(declaration))) => declaration,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(declaration),
+                    ..
+                })) => declaration,
+                _ => continue,
+            };
+
+            for declarator in &declaration.decls {
+                if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init) {
+                    bindings.insert(binding.id.sym.to_string(), &**init);
+                }
+            }
+        }
+
+        Evaluator { bindings }
+    }
+
+    fn evaluate(&self, expr: &Expr, depth: usize) -> Value {
+        if depth > MAX_DEPTH {
+            return Value::Null;
+        }
+
+        match expr {
+            Expr::Lit(Lit::Str(string)) => Value::String(string.value.to_string()),
+            Expr::Lit(Lit::Bool(boolean)) => Value::Bool(boolean.value),
+            Expr::Lit(Lit::Num(number)) => Number::from_f64(number.value)
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            Expr::Tpl(template) if template.exprs.is_empty() => Value::String(
+                template
+                    .quasis
+                    .iter()
+                    .map(|quasi| quasi.raw.to_string())
+                    .collect(),
+            ),
+            Expr::Array(array) => Value::Array(
+                array
+                    .elems
+                    .iter()
+                    .flatten()
+                    .flat_map(|element| {
+                        let value = self.evaluate(&element.expr, depth + 1);
+                        match (element.spread, value) {
+                            (Some(_), Value::Array(values)) => values,
+                            (Some(_), _) => Vec::new(),
+                            (None, value) => vec![value],
+                        }
+                    })
+                    .collect(),
+            ),
+            Expr::Object(object) => {
+                let mut values = Map::new();
+
+                for property in &object.props {
+                    match property {
+                        PropOrSpread::Spread(spread) => {
+                            if let Value::Object(spread) = self.evaluate(&spread.expr, depth + 1) {
+                                values.extend(spread);
+                            }
+                        }
+                        PropOrSpread::Prop(property) => match &**property {
+                            Prop::KeyValue(property) => {
+                                if let Some(key) = get_property_key(&property.key) {
+                                    values.insert(key, self.evaluate(&property.value, depth + 1));
+                                }
+                            }
+                            Prop::Shorthand(ident) => {
+                                values.insert(
+                                    ident.sym.to_string(),
+                                    self.evaluate(&Expr::Ident(ident.clone()), depth + 1),
+                                );
+                            }
+                            _ => {}
+                        },
+                    }
+                }
+
+                Value::Object(values)
+            }
+            Expr::Ident(ident) => self
+                .bindings
+                .get(&*ident.sym)
+                .map(|init| self.evaluate(init, depth + 1))
+                .unwrap_or(Value::Null),
+            Expr::Paren(paren) => self.evaluate(&paren.expr, depth + 1),
+            Expr::TsAs(ts_as) => self.evaluate(&ts_as.expr, depth + 1),
+            Expr::TsConstAssertion(assertion) => self.evaluate(&assertion.expr, depth + 1),
+            Expr::Call(call) => self.evaluate_call(call, depth),
+            Expr::Arrow(arrow) => match &arrow.body {
+                BlockStmtOrExpr::Expr(expr) => self.evaluate(expr, depth + 1),
+                BlockStmtOrExpr::BlockStmt(block) => self.evaluate_block(block, depth),
+            },
+            // Babel configs may export a function of the config api.
+            Expr::Fn(function) => match &function.function.body {
+                Some(block) => self.evaluate_block(block, depth),
+                None => Value::Null,
+            },
+            _ => Value::Null,
+        }
+    }
+
+    /// Evaluate the value returned by the last `return` statement of a function body.
+    fn evaluate_block(&self, block: &BlockStmt, depth: usize) -> Value {
+        block
+            .stmts
+            .iter()
+            .filter_map(|statement| match statement {
+                Stmt::Return(statement) => statement.arg.as_ref(),
+                _ => None,
+            })
+            .last()
+            .map(|arg| self.evaluate(arg, depth + 1))
+            .unwrap_or(Value::Null)
+    }
+
+    fn evaluate_call(&self, call: &CallExpr, depth: usize) -> Value {
+        let callee = match &call.callee {
+            Callee::Expr(callee) => &**callee,
+            _ => return Value::Null,
+        };
+
+        let first_argument = match call.args.first() {
+            Some(argument) => self.evaluate(&argument.expr, depth + 1),
+            None => return Value::Null,
+        };
+
+        match (callee, first_argument) {
+            // `require.resolve("babel-loader")` points to a module.
+            (Expr::Member(member), Value::String(specifier))
+                if is_member(member, "require", "resolve") =>
+            {
+                Value::String(specifier)
+            }
+            // Helpers like `defineConfig({ ... })` return their configuration argument.
+            (_, value @ (Value::Object(_) | Value::Array(_))) => value,
+            _ => Value::Null,
+        }
+    }
+}
+
+fn get_property_key(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(string) => Some(string.value.to_string()),
+        PropName::Num(number) => Some(number.value.to_string()),
+        _ => None,
+    }
+}
+
+fn is_member(member: &MemberExpr, object: &str, property: &str) -> bool {
+    let is_object = matches!(&*member.obj, Expr::Ident(ident) if &*ident.sym == object);
+    let is_property = matches!(&member.prop, MemberProp::Ident(ident) if &*ident.sym == property);
+
+    is_object && is_property
+}
+
+fn is_module_exports(target: &PatOrExpr) -> bool {
+    let target = match target {
+        PatOrExpr::Expr(expr) => &**expr,
+        PatOrExpr::Pat(pat) => match &**pat {
+            Pat::Expr(expr) => &**expr,
+            _ => return false,
+        },
+    };
+
+    matches!(target, Expr::Member(member) if is_member(member, "module", "exports"))
+}
@@ -0,0 +1,25 @@
+use crate::package::Package;
+use crate::util::extract_package_name::extract_package_name;
+
+/// Get a package from a webpack loader reference.
+///
+/// Loaders may be referenced without their `-loader` suffix, e.g. `css` for `css-loader`,
+/// so the suffixed name is used when the package declares it.
+pub fn extract_loader_name(loader: &str, package: &Package) -> Option<String> {
+    if loader.starts_with('.') || loader.starts_with('/') {
+        return None;
+    }
+
+    let name = extract_package_name(loader)?;
+
+    if name.ends_with("-loader") {
+        return Some(name);
+    }
+
+    let loader_name = format!("{name}-loader");
+    if package.is_any_dependency(&loader_name) {
+        Some(loader_name)
+    } else {
+        Some(name)
+    }
+}
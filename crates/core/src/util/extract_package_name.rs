@@ -0,0 +1,20 @@
+use regex::Regex;
+
+/// Get a package name from a module specifier, e.g. `@scope/name` from `@scope/name/lib/file`.
+pub fn extract_package_name(specifier: &str) -> Option<String> {
+    let scope_pattern = Regex::new(r"^(?:(@[^/]+)[/]+)([^/]+)[/]?").unwrap();
+    let base_pattern = Regex::new(r"^([^/]+)[/]?").unwrap();
+    let scope_pattern_test = Regex::new(r"^@").unwrap();
+
+    if scope_pattern_test.is_match(specifier) {
+        let captures = scope_pattern.captures(specifier)?;
+
+        match (captures.get(1), captures.get(2)) {
+            (Some(first), Some(second)) => Some(first.as_str().to_owned() + "/" + second.as_str()),
+            _ => None,
+        }
+    } else {
+        let captures = base_pattern.captures(specifier)?;
+        captures.get(1).map(|v| v.as_str().to_owned())
+    }
+}
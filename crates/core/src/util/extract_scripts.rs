@@ -0,0 +1,150 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static LANG_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\blang\s*=\s*["']?([\w-]+)"#).unwrap());
+
+/// Scripts of a single-file component.
+pub struct ComponentScripts {
+    /// The `lang` attribute of the first script block declaring one.
+    pub lang: Option<String>,
+    /// The component source with everything outside of script blocks blanked out,
+    /// so spans still point to the original lines and columns.
+    pub source: String,
+}
+
+/// Extract `<script>` blocks from a single-file component source.
+///
+/// Covers both Vue (`<script>`, `<script setup>`) and Svelte
+/// (instance and `context="module"`) components. Script tags in HTML comments are skipped,
+/// as are `>` in quoted attributes and `</script>` in strings and comments of scripts.
+pub fn extract_scripts(source: &str) -> ComponentScripts {
+    let mut lang = None;
+    let mut masked = String::with_capacity(source.len());
+    let mut last_end = 0;
+    let mut index = 0;
+
+    while index < source.len() {
+        if source.as_bytes()[index..].starts_with(b"<!--") {
+            index = find_from(source, index + 4, "-->").map_or(source.len(), |end| end + 3);
+            continue;
+        }
+
+        if !is_tag(source, index, "<script") {
+            index += 1;
+            continue;
+        }
+
+        let attributes_start = index + "<script".len();
+        let content_start = match find_tag_end(source, attributes_start) {
+            Some(tag_end) => tag_end + 1,
+            None => break,
+        };
+        let content_end = find_script_end(source, content_start);
+
+        if lang.is_none() {
+            lang = LANG_PATTERN
+                .captures(&source[attributes_start..content_start - 1])
+                .and_then(|captures| captures.get(1))
+                .map(|lang| lang.as_str().to_lowercase());
+        }
+
+        masked.extend(source[last_end..content_start].chars().map(blank));
+        masked.push_str(&source[content_start..content_end]);
+        last_end = content_end;
+        index = content_end + 1;
+    }
+
+    masked.extend(source[last_end..].chars().map(blank));
+
+    ComponentScripts {
+        lang,
+        source: masked,
+    }
+}
+
+/// Check if a tag like `<script` or `</script` starts at an index, ignoring the case.
+fn is_tag(source: &str, index: usize, tag: &str) -> bool {
+    let name_matches = source
+        .get(index..index + tag.len())
+        .map_or(false, |name| name.eq_ignore_ascii_case(tag));
+
+    name_matches
+        && source[index + tag.len()..]
+            .chars()
+            .next()
+            .map_or(false, |next| {
+                next.is_whitespace() || next == '>' || next == '/'
+            })
+}
+
+/// Get the index of the `>` ending a start tag, skipping quoted attribute values.
+fn find_tag_end(source: &str, start: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'>' => return Some(index),
+            quote @ (b'"' | b'\'') => {
+                index = find_from(source, index + 1, &(quote as char).to_string())?;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    None
+}
+
+/// Get the index of the `</script>` closing a script, skipping strings and comments.
+fn find_script_end(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut index = start;
+
+    while index < bytes.len() {
+        index = match (bytes[index], bytes.get(index + 1)) {
+            (b'/', Some(b'/')) => find_from(source, index, "\n").unwrap_or(bytes.len()),
+            (b'/', Some(b'*')) => {
+                find_from(source, index + 2, "*/").map_or(bytes.len(), |end| end + 2)
+            }
+            (quote @ (b'"' | b'\'' | b'`'), _) => skip_string(bytes, index + 1, quote),
+            (b'<', _) if is_tag(source, index, "</script") => return index,
+            _ => index + 1,
+        };
+    }
+
+    bytes.len()
+}
+
+/// Get the index after the quote closing a string. Unterminated strings other than
+/// template literals end at the line end.
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 1,
+            b'\n' if quote != b'`' => return index,
+            byte if byte == quote => return index + 1,
+            _ => {}
+        }
+        index += 1;
+    }
+
+    bytes.len()
+}
+
+fn find_from(source: &str, start: usize, pattern: &str) -> Option<usize> {
+    source
+        .get(start..)?
+        .find(pattern)
+        .map(|index| start + index)
+}
+
+fn blank(character: char) -> char {
+    match character {
+        '\n' | '\r' => character,
+        _ => ' ',
+    }
+}
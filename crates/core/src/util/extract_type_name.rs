@@ -3,7 +3,7 @@ use regex::Regex;
 
 /// Get a DefinitelyTyped dependency for a given dependency.
 pub fn extract_type_name(dependency: &str) -> String {
-    if is_core_module(dependency) {
+    if is_core_module(dependency, None) {
         return "@types/node".to_owned();
     }
 
@@ -1,10 +1,8 @@
-use std::path::Path;
+use crate::resolver::Resolver;
 
-use crate::util::load_module::load_module;
-
-/// Check if a dependency is a bin dependency for a directory.
-pub fn is_bin_dependency(directory: &Path, dependency: &str) -> bool {
-    let dependency_module = load_module(&directory.join("node_modules").join(dependency));
+/// Check if a dependency installed for a directory is a bin dependency.
+pub fn is_bin_dependency(resolver: &Resolver, dependency: &str) -> bool {
+    let dependency_module = resolver.load_module(dependency);
 
     match dependency_module {
         Ok(dependency_module) => dependency_module.bin.is_some(),
@@ -1,59 +1,178 @@
-const CORE_MODULES: [&str; 52] = [
-    "assert",
-    "assert/strict",
-    "async_hooks",
-    "buffer",
-    "child_process",
-    "cluster",
-    "console",
-    "constants",
-    "crypto",
-    "dgram",
-    "diagnostics_channel",
-    "dns",
-    "dns/promises",
-    "domain",
-    "events",
-    "fs",
-    "fs/promises",
-    "http",
-    "http2",
-    "https",
-    "inspector",
-    "module",
-    "net",
-    "os",
-    "path",
-    "path/posix",
-    "path/win32",
-    "perf_hooks",
-    "process",
-    "punycode",
-    "querystring",
-    "readline",
-    "repl",
-    "stream",
-    "stream/consumers",
-    "stream/promises",
-    "stream/web",
-    "string_decoder",
-    "sys",
-    "timers",
-    "timers/promises",
-    "tls",
-    "trace_events",
-    "tty",
-    "url",
-    "util",
-    "util/types",
-    "v8",
-    "vm",
-    "wasi",
-    "worker_threads",
-    "zlib",
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Core modules with the Node version they were added in.
+const CORE_MODULES: [(&str, NodeVersion); 54] = [
+    ("assert", (0, 1)),
+    ("assert/strict", (15, 0)),
+    ("async_hooks", (8, 1)),
+    ("buffer", (0, 1)),
+    ("child_process", (0, 1)),
+    ("cluster", (0, 7)),
+    ("console", (0, 1)),
+    ("constants", (0, 1)),
+    ("crypto", (0, 1)),
+    ("dgram", (0, 1)),
+    ("diagnostics_channel", (15, 1)),
+    ("dns", (0, 1)),
+    ("dns/promises", (15, 0)),
+    ("domain", (0, 7)),
+    ("events", (0, 1)),
+    ("fs", (0, 1)),
+    ("fs/promises", (14, 0)),
+    ("http", (0, 1)),
+    ("http2", (8, 4)),
+    ("https", (0, 1)),
+    ("inspector", (8, 0)),
+    ("inspector/promises", (19, 0)),
+    ("module", (0, 1)),
+    ("net", (0, 1)),
+    ("os", (0, 1)),
+    ("path", (0, 1)),
+    ("path/posix", (15, 3)),
+    ("path/win32", (15, 3)),
+    ("perf_hooks", (8, 5)),
+    ("process", (0, 1)),
+    ("punycode", (0, 5)),
+    ("querystring", (0, 1)),
+    ("readline", (0, 1)),
+    ("readline/promises", (17, 0)),
+    ("repl", (0, 1)),
+    ("stream", (0, 1)),
+    ("stream/consumers", (16, 7)),
+    ("stream/promises", (15, 0)),
+    ("stream/web", (16, 5)),
+    ("string_decoder", (0, 1)),
+    ("sys", (0, 1)),
+    ("timers", (0, 1)),
+    ("timers/promises", (15, 0)),
+    ("tls", (0, 1)),
+    ("trace_events", (10, 0)),
+    ("tty", (0, 1)),
+    ("url", (0, 1)),
+    ("util", (0, 1)),
+    ("util/types", (15, 3)),
+    ("v8", (1, 0)),
+    ("vm", (0, 1)),
+    ("wasi", (13, 3)),
+    ("worker_threads", (10, 5)),
+    ("zlib", (0, 1)),
+];
+
+/// Core modules which only exist with the `node:` scheme, with the Node version they were added in.
+const SCHEME_ONLY_CORE_MODULES: [(&str, NodeVersion); 4] = [
+    ("sea", (20, 12)),
+    ("sqlite", (22, 5)),
+    ("test", (18, 0)),
+    ("test/reporters", (19, 9)),
 ];
 
-/// Check if a module is core node module.
-pub fn is_core_module(module: &str) -> bool {
-    CORE_MODULES.contains(&module)
+/// The first Node release, the lowest version of ranges with only upper bounds like `<16`.
+const FIRST_NODE_VERSION: NodeVersion = (0, 1);
+
+/// A Node version as `(major, minor)`.
+pub type NodeVersion = (u32, u32);
+
+/// Check if a module is core node module in a Node version, or in the latest one without it.
+///
+/// Modules with the `node:` scheme also include the ones which only exist
+/// with the scheme like `node:test` and `node:sqlite`.
+pub fn is_core_module(module: &str, node_version: Option<NodeVersion>) -> bool {
+    let is_added = |modules: &[(&str, NodeVersion)], module: &str| {
+        modules.iter().any(|(name, added)| {
+            *name == module && node_version.map_or(true, |version| *added <= version)
+        })
+    };
+
+    match module.strip_prefix("node:") {
+        Some(module) => {
+            is_added(&CORE_MODULES, module) || is_added(&SCHEME_ONLY_CORE_MODULES, module)
+        }
+        None => is_added(&CORE_MODULES, module),
+    }
+}
+
+/// Get the lowest Node version allowed by a version or a range like `>=18.12 || ^20`.
+///
+/// Ranges allowing any version, like `*`, and malformed ranges have no lowest version.
+pub fn parse_node_version(range: &str) -> Option<NodeVersion> {
+    range
+        .split("||")
+        .map(parse_lowest_version)
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .min()
+}
+
+/// Get the lowest Node version allowed by a range without alternatives,
+/// like `>=16 <20`, `16 - 18` or `^18.12`.
+fn parse_lowest_version(range: &str) -> Option<NodeVersion> {
+    static OPERATOR_SPACE_PATTERN: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(<=|>=|<|>|=|\^|~)\s+").unwrap());
+
+    let range = OPERATOR_SPACE_PATTERN.replace_all(range, "$1");
+    let comparators: Vec<&str> = range.split_whitespace().collect();
+
+    if let [from, "-", _] = comparators.as_slice() {
+        let (major, minor, _) = parse_version(from)?;
+        return Some((major?, minor.unwrap_or(0)));
+    }
+
+    let mut lowest_version = None;
+    let mut has_upper_bound = false;
+
+    for comparator in comparators {
+        let operator_length = comparator
+            .find(|character: char| character.is_ascii_alphanumeric() || character == '*')
+            .unwrap_or(comparator.len());
+        let (operator, version) = comparator.split_at(operator_length);
+        let (major, minor, patch) = parse_version(version)?;
+
+        let version = match (operator, major, minor, patch) {
+            ("<" | "<=", _, _, _) => {
+                has_upper_bound = true;
+                continue;
+            }
+            (_, None, _, _) => continue,
+            (">", Some(major), None, _) => (major + 1, 0),
+            (">", Some(major), Some(minor), None) => (major, minor + 1),
+            (">" | ">=" | "=" | "^" | "~" | "", Some(major), minor, _) => {
+                (major, minor.unwrap_or(0))
+            }
+            _ => return None,
+        };
+
+        lowest_version = lowest_version.max(Some(version));
+    }
+
+    lowest_version.or_else(|| has_upper_bound.then(|| FIRST_NODE_VERSION))
+}
+
+/// Parse a version like `18.12.1` or `v18.x` into its major, minor and patch parts,
+/// without the parts which are wildcards or missing.
+fn parse_version(version: &str) -> Option<(Option<u32>, Option<u32>, Option<u32>)> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    // Prereleases and build metadata don't change the lowest major and minor versions.
+    let version = version
+        .split(|character| character == '-' || character == '+')
+        .next()?;
+
+    let mut parts = version.split('.').map(|part| match part {
+        "x" | "X" | "*" => Ok(None),
+        part => part.parse().map(Some),
+    });
+
+    let major = parts.next().unwrap_or(Ok(None)).ok()?;
+    let minor = parts.next().unwrap_or(Ok(None)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(None)).ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((
+        major,
+        minor.filter(|_| major.is_some()),
+        patch.filter(|_| minor.is_some()),
+    ))
 }
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::parser::Parser;
+use crate::util::evaluate_module::evaluate_module;
+
+/// Load a configuration file as a JSON value.
+///
+/// JSON files may contain comments and trailing commas, JavaScript files are evaluated
+/// statically and files without a known extension are read as JSON or else as YAML.
+pub fn load_config(path: &Path) -> Option<Value> {
+    if !path.is_file() {
+        return None;
+    }
+
+    log::debug!("load config {:#?}", path);
+
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+
+    if let "js" | "cjs" | "mjs" | "ts" | "cts" | "mts" = extension {
+        let (module, _, _) = Parser::default().parse_file(path)?;
+        return evaluate_module(&module);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|error| {
+            log::error!("failed to load {:#?}", error);
+        })
+        .ok()?;
+
+    match extension {
+        "json" | "jsonc" | "json5" => parse_json(&content),
+        "yaml" | "yml" => parse_yaml(&content),
+        _ => parse_json(&content).or_else(|| parse_yaml(&content)),
+    }
+}
+
+fn parse_json(content: &str) -> Option<Value> {
+    json5::from_str(content)
+        .map_err(|error| {
+            log::debug!("failed to parse json {:#?}", error);
+        })
+        .ok()
+}
+
+fn parse_yaml(content: &str) -> Option<Value> {
+    serde_yaml::from_str(content)
+        .map_err(|error| {
+            log::debug!("failed to parse yaml {:#?}", error);
+        })
+        .ok()
+}
@@ -1,5 +1,14 @@
+pub(crate) mod build_walker;
+pub(crate) mod evaluate_module;
+pub(crate) mod extract_loader_name;
+pub(crate) mod extract_package_name;
+pub(crate) mod extract_scripts;
 pub(crate) mod extract_type_name;
 pub(crate) mod is_bin_dependency;
 pub(crate) mod is_core_module;
 pub(crate) mod is_module;
+pub(crate) mod load_config;
 pub(crate) mod load_module;
+pub(crate) mod resolve_package_map;
+pub(crate) mod split_webpack_request;
+pub(crate) mod strip_flow_syntax;
@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// Resolve a specifier through an `imports` or `exports` map of a package, e.g.
+/// `./src/internal/utils.js` for `#internal/utils` with `"#internal/*": "./src/internal/*.js"`.
+///
+/// Returns targets of every condition, since any of them may be used.
+pub fn resolve_package_map(map: &BTreeMap<String, Value>, specifier: &str) -> Vec<String> {
+    if let Some(target) = map.get(specifier) {
+        return get_targets(target, None);
+    }
+
+    // The most specific pattern wins.
+    let pattern = map
+        .iter()
+        .filter_map(|(key, target)| {
+            let replacement = match key.split_once('*') {
+                Some((prefix, suffix)) => {
+                    let matches = specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix);
+                    matches.then(|| &specifier[prefix.len()..specifier.len() - suffix.len()])
+                }
+                // Legacy folder mappings like `"./features/": "./src/features/"`.
+                None if key.ends_with('/') => specifier.strip_prefix(key.as_str()),
+                None => None,
+            }?;
+
+            Some((key, target, replacement))
+        })
+        .max_by_key(|(key, _, _)| key.len());
+
+    match pattern {
+        Some((key, target, replacement)) if key.contains('*') => {
+            get_targets(target, Some(replacement))
+        }
+        Some((_, target, rest)) => get_targets(target, None)
+            .into_iter()
+            .map(|target| format!("{target}{rest}"))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Get targets of a mapping, which is a path, an array of fallbacks or a map of conditions.
+fn get_targets(target: &Value, replacement: Option<&str>) -> Vec<String> {
+    match target {
+        Value::String(target) => match replacement {
+            Some(replacement) => vec![target.replace('*', replacement)],
+            None => vec![target.to_owned()],
+        },
+        Value::Array(targets) => targets
+            .iter()
+            .flat_map(|target| get_targets(target, replacement))
+            .collect(),
+        Value::Object(conditions) => conditions
+            .values()
+            .flat_map(|target| get_targets(target, replacement))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,16 @@
+/// Split a webpack request into its loaders followed by the resource, e.g.
+/// `style-loader`, `css-loader` and `./a.css` from `!style-loader!css-loader?modules!./a.css`.
+///
+/// Prefixes disabling configured loaders (`!`, `!!`, `-!`) and `?query` parameters are dropped.
+pub fn split_webpack_request(request: &str) -> Vec<&str> {
+    let request = ["-!", "!!", "!"]
+        .iter()
+        .find_map(|prefix| request.strip_prefix(prefix))
+        .unwrap_or(request);
+
+    request
+        .split('!')
+        .map(|part| part.split_once('?').map_or(part, |(part, _)| part))
+        .filter(|part| !part.is_empty())
+        .collect()
+}
@@ -0,0 +1,533 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+static IMPORT_TYPEOF_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bimport(\s+)typeof\b").unwrap());
+static OPAQUE_TYPE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:declare\s+)?(?:export\s+)?(?:declare\s+)?opaque\s+type\s+[\w$]+").unwrap()
+});
+static DECLARE_EXPORT_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bdeclare(\s+)export(\s+)(default\b|\*|\{)?").unwrap());
+static DECLARE_MODULE_EXPORTS_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bdeclare\s+module\.exports\s*:").unwrap());
+static TYPE_ALIAS_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\btype\s+[\w$]+\s*").unwrap());
+static EXACT_OBJECT_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\||\|\}").unwrap());
+static MAYBE_TYPE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"([:<]\s*)\?([\w{(\[])").unwrap());
+static VARIANCE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"([{,]\s*)[+-](\w+\s*:)").unwrap());
+
+/// Keywords a parenthesized expression may follow, unlike parameters of a call or a function.
+const EXPRESSION_KEYWORDS: [&str; 10] = [
+    "return", "await", "yield", "typeof", "case", "in", "of", "void", "delete", "throw",
+];
+
+/// Rewrite Flow-only syntax into its closest TypeScript counterpart, so Flow typed
+/// modules can be parsed as TypeScript.
+///
+/// Every rewrite keeps the length of the source, so spans still point to
+/// the original lines and columns.
+pub fn strip_flow_syntax(source: &str) -> String {
+    let source = IMPORT_TYPEOF_PATTERN.replace_all(source, "import${1}type  ");
+    let source = DECLARE_EXPORT_PATTERN.replace_all(&source, |captures: &Captures| {
+        match captures.get(3) {
+            // `export default`, `export *` and `export {}` can't be declared in TypeScript.
+            Some(rest) => format!(
+                "       {}export{}{}",
+                &captures[1],
+                &captures[2],
+                rest.as_str()
+            ),
+            None => format!("export{}declare{}", &captures[1], &captures[2]),
+        }
+    });
+
+    let mut bytes = source.into_owned().into_bytes();
+    let is_code = get_code_mask(&bytes);
+    strip_opaque_types(&mut bytes, &is_code);
+    strip_module_exports(&mut bytes, &is_code);
+    strip_type_spreads(&mut bytes, &is_code);
+    strip_type_parameter_bounds(&mut bytes, &is_code);
+
+    // Only whole characters are blanked, so the source stays valid UTF-8.
+    let source = String::from_utf8(bytes).expect("blanked source to be valid UTF-8");
+    let source =
+        EXACT_OBJECT_PATTERN.replace_all(&source, |captures: &Captures| match &captures[0] {
+            "{|" => "{ ",
+            _ => " }",
+        });
+    let source = MAYBE_TYPE_PATTERN.replace_all(&source, "$1 $2");
+    let source = VARIANCE_PATTERN.replace_all(&source, "$1 $2");
+
+    let mut bytes = source.into_owned().into_bytes();
+    strip_type_casts(&mut bytes, &is_code);
+
+    String::from_utf8(bytes).expect("blanked source to be valid UTF-8")
+}
+
+/// Rewrite opaque types into type aliases, e.g. `opaque type ID: string = string`
+/// to `       type ID         = string`.
+///
+/// Declared opaque types have no underlying type, so they are blanked out entirely.
+fn strip_opaque_types(bytes: &mut [u8], is_code: &[bool]) {
+    let source = String::from_utf8_lossy(bytes).into_owned();
+
+    for declaration in OPAQUE_TYPE_PATTERN.find_iter(&source) {
+        if !is_code[declaration.start()] {
+            continue;
+        }
+
+        let mut index = skip_type_parameters(bytes, is_code, declaration.end());
+        index = skip_whitespace(bytes, index);
+        let supertype = if bytes.get(index) == Some(&b':') {
+            let start = index;
+            index = find_type_end(bytes, is_code, index + 1, b"=;\n");
+            Some(start..index)
+        } else {
+            None
+        };
+
+        if bytes.get(index) == Some(&b'=') {
+            let keyword = declaration.as_str().find("opaque").unwrap() + declaration.start();
+            blank(bytes, keyword..keyword + "opaque".len());
+            if let Some(supertype) = supertype {
+                blank(bytes, supertype);
+            }
+        } else {
+            let end = if bytes.get(index) == Some(&b';') {
+                index + 1
+            } else {
+                index
+            };
+            blank(bytes, declaration.start()..end);
+        }
+    }
+}
+
+/// Blank out the declared type of CommonJS exports, e.g. `declare module.exports: T;`,
+/// which has no TypeScript counterpart.
+fn strip_module_exports(bytes: &mut [u8], is_code: &[bool]) {
+    let source = String::from_utf8_lossy(bytes).into_owned();
+
+    for declaration in DECLARE_MODULE_EXPORTS_PATTERN.find_iter(&source) {
+        if !is_code[declaration.start()] {
+            continue;
+        }
+
+        let end = find_type_end(bytes, is_code, declaration.end(), b";\n");
+        let end = if bytes.get(end) == Some(&b';') {
+            end + 1
+        } else {
+            end
+        };
+        blank(bytes, declaration.start()..end);
+    }
+}
+
+/// Blank out spreads of object types, e.g. `{...A, b: B}` to `{      b: B}`.
+///
+/// Only the bodies of type aliases and exact object types are known to be types,
+/// the same syntax in an expression is a spread of an object.
+fn strip_type_spreads(bytes: &mut [u8], is_code: &[bool]) {
+    let source = String::from_utf8_lossy(bytes).into_owned();
+    let mut types = Vec::new();
+
+    for alias in TYPE_ALIAS_PATTERN.find_iter(&source) {
+        if !is_code[alias.start()] || (alias.start() > 0 && bytes[alias.start() - 1] == b'.') {
+            continue;
+        }
+
+        let index = skip_type_parameters(bytes, is_code, alias.end());
+        let index = skip_whitespace(bytes, index);
+        if bytes.get(index) == Some(&b'=') && bytes.get(index + 1) != Some(&b'>') {
+            types.push(index + 1..find_type_end(bytes, is_code, index + 1, b";\n"));
+        }
+    }
+
+    for (index, window) in bytes.windows(2).enumerate() {
+        if window == b"{|" && is_code[index] {
+            types.push(index..find_type_end(bytes, is_code, index + 1, b""));
+        }
+    }
+
+    for range in types {
+        let mut brackets = Vec::new();
+        let mut index = range.start;
+
+        while index < range.end {
+            if !is_code[index] {
+                index += 1;
+                continue;
+            }
+
+            match bytes[index] {
+                byte @ (b'(' | b'[' | b'{' | b'<') => brackets.push(byte),
+                b')' | b']' | b'}' | b'>' if bytes[index - 1] != b'=' => {
+                    brackets.pop();
+                }
+                // Rest parameters of function types are spread too, but only inside parentheses.
+                b'.' if brackets.last() == Some(&b'{') && bytes[index..].starts_with(b"...") => {
+                    let end = find_type_end(bytes, is_code, index + 3, b",;|");
+                    let end = if matches!(bytes.get(end), Some(b',' | b';')) {
+                        end + 1
+                    } else {
+                        end
+                    };
+                    blank(bytes, index..end);
+                    index = end;
+                    continue;
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+    }
+}
+
+/// Blank out bounds of type parameters, e.g. `<T: Base>` to `<T      >`.
+fn strip_type_parameter_bounds(bytes: &mut [u8], is_code: &[bool]) {
+    for opening in 0..bytes.len() {
+        if bytes[opening] != b'<' || !is_code[opening] {
+            continue;
+        }
+
+        // Type parameters follow the name of a declaration, or start a generic arrow function.
+        let follows_name = opening > 0 && is_identifier_byte(bytes[opening - 1]);
+        let starts_arrow_function = matches!(
+            source_before(bytes, opening).last(),
+            None | Some(b'=' | b'(' | b',' | b':' | b'?')
+        );
+        if !follows_name && !starts_arrow_function {
+            continue;
+        }
+
+        let (closing, bounds) = match parse_type_parameters(bytes, is_code, opening) {
+            Some(parsed) => parsed,
+            None => continue,
+        };
+        if !follows_name && !source_after(bytes, closing).starts_with(b"(") {
+            continue;
+        }
+
+        for bound in bounds {
+            blank(bytes, bound);
+        }
+    }
+}
+
+/// Parse the type parameters starting at `opening`, and get their closing `>`
+/// and the ranges of their bounds.
+fn parse_type_parameters(
+    bytes: &[u8],
+    is_code: &[bool],
+    opening: usize,
+) -> Option<(usize, Vec<std::ops::Range<usize>>)> {
+    let mut bounds = Vec::new();
+    let mut index = opening + 1;
+
+    loop {
+        index = skip_whitespace(bytes, index);
+        if matches!(bytes.get(index), Some(b'+' | b'-')) {
+            index += 1;
+        }
+
+        let name_end = bytes[index..]
+            .iter()
+            .position(|&byte| !is_identifier_byte(byte))
+            .map_or(bytes.len(), |end| index + end);
+        if name_end == index || !is_code[index] {
+            return None;
+        }
+        index = skip_whitespace(bytes, name_end);
+
+        if bytes.get(index) == Some(&b':') {
+            let end = find_type_end(bytes, is_code, index + 1, b",>=;");
+            bounds.push(index..end);
+            index = end;
+        }
+        if bytes.get(index) == Some(&b'=') {
+            index = find_type_end(bytes, is_code, index + 1, b",>;");
+        }
+
+        match bytes.get(index) {
+            Some(b',') => index += 1,
+            Some(b'>') if !bounds.is_empty() => return Some((index, bounds)),
+            _ => return None,
+        }
+    }
+}
+
+/// Skip the type parameters of a declaration at `index`, if there are any.
+fn skip_type_parameters(bytes: &[u8], is_code: &[bool], index: usize) -> usize {
+    let index = skip_whitespace(bytes, index);
+    if bytes.get(index) != Some(&b'<') {
+        return index;
+    }
+
+    let end = find_type_end(bytes, is_code, index + 1, b">;");
+    if bytes.get(end) == Some(&b'>') {
+        end + 1
+    } else {
+        index
+    }
+}
+
+/// Find the end of a type starting at `start`, which is the first top-level byte
+/// of `terminators`, or an unmatched closing bracket.
+///
+/// A top-level line break only ends the type if it doesn't obviously continue
+/// on the next line.
+fn find_type_end(bytes: &[u8], is_code: &[bool], start: usize, terminators: &[u8]) -> usize {
+    let mut depth = 0usize;
+    let mut index = start;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if !is_code[index] {
+            index += 1;
+            continue;
+        }
+
+        // The arrow of a function type isn't a closing angle bracket.
+        if byte == b'=' && bytes.get(index + 1) == Some(&b'>') {
+            index += 2;
+            continue;
+        }
+
+        match byte {
+            b'(' | b'[' | b'{' | b'<' => depth += 1,
+            b')' | b']' | b'}' | b'>' if depth > 0 => depth -= 1,
+            _ if depth > 0 => {}
+            b')' | b']' | b'}' | b'>' if !terminators.contains(&byte) => return index,
+            b'\n' if terminators.contains(&b'\n') && !continues_type(bytes, index) => return index,
+            _ if terminators.contains(&byte) => return index,
+            _ => {}
+        }
+        index += 1;
+    }
+
+    bytes.len()
+}
+
+/// Check if a type continues after the line break at `index`.
+fn continues_type(bytes: &[u8], index: usize) -> bool {
+    let before = source_before(bytes, index);
+    let after = source_after(bytes, index);
+
+    matches!(
+        before.last(),
+        Some(b'=' | b'|' | b'&' | b':' | b',' | b'?' | b'<')
+    ) || matches!(after.first(), Some(b'|' | b'&'))
+}
+
+/// Blank out the types of type casts, e.g. `(value: any)` to `(value     )`.
+///
+/// A cast is a parenthesized expression with a top-level `:` which is neither
+/// the parameter list of an arrow function nor of a call or a function declaration.
+///
+/// The source is scanned once. An expression followed by a `:` looks like
+/// the parameters of an arrow function with a return type, until an outer cast
+/// turns out to own that `:`, e.g. `((value: any): T)`.
+fn strip_type_casts(bytes: &mut [u8], is_code: &[bool]) {
+    struct Group {
+        opening: usize,
+        bracket: u8,
+        colon: Option<usize>,
+        is_conditional: bool,
+        has_arrow: bool,
+    }
+
+    let source = bytes.to_vec();
+    let mut groups: Vec<Group> = Vec::new();
+    // Casts which are followed by a `:`, by the position of that `:`.
+    let mut pending = HashMap::new();
+
+    for (index, &byte) in source.iter().enumerate() {
+        if !is_code[index] {
+            continue;
+        }
+
+        match byte {
+            b'(' | b'[' | b'{' => groups.push(Group {
+                opening: index,
+                bracket: byte,
+                colon: None,
+                is_conditional: false,
+                has_arrow: false,
+            }),
+            b')' | b']' | b'}' => {
+                let group = match groups.pop() {
+                    Some(group) if group.bracket == b'(' && byte == b')' => group,
+                    _ => continue,
+                };
+                let colon = match group.colon {
+                    Some(colon) => colon,
+                    None => continue,
+                };
+                // A conditional expression, `(a ? b : c)`, or an optional parameter.
+                if group.is_conditional {
+                    continue;
+                }
+                // An arrow function with a return type, `((value: T): T => value)`.
+                if group.has_arrow && pending.contains_key(&colon) {
+                    continue;
+                }
+
+                match classify_group(&source, group.opening, index) {
+                    GroupKind::Cast => blank_cast(bytes, &mut pending, colon..index),
+                    GroupKind::FollowedByColon(next) => {
+                        pending.insert(next, colon..index);
+                    }
+                    GroupKind::Other => {}
+                }
+            }
+            b'?' => {
+                if let Some(group) = groups.last_mut() {
+                    group.is_conditional = true;
+                }
+            }
+            b':' => {
+                if let Some(group) = groups.last_mut() {
+                    if group.colon.is_none() {
+                        group.colon = Some(index);
+                    }
+                }
+            }
+            b'=' if source.get(index + 1) == Some(&b'>') => {
+                if let Some(group) = groups.last_mut() {
+                    group.has_arrow = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+enum GroupKind {
+    Cast,
+    /// A cast, unless it is the parameter list of an arrow function with a return type.
+    FollowedByColon(usize),
+    Other,
+}
+
+/// Classify the parenthesized group between `opening` and `closing`, which has
+/// a top-level `:`.
+fn classify_group(bytes: &[u8], opening: usize, closing: usize) -> GroupKind {
+    let before = source_before(bytes, opening);
+    let follows_expression_keyword = EXPRESSION_KEYWORDS
+        .iter()
+        .any(|keyword| before.ends_with(keyword.as_bytes()) && !is_word_end(before, keyword));
+    let follows_callee = before.last().map_or(false, |&byte| {
+        is_identifier_byte(byte) || matches!(byte, b')' | b']')
+    });
+
+    if follows_callee && !follows_expression_keyword {
+        return GroupKind::Other;
+    }
+
+    // Parameters of arrow functions are followed by the arrow or a return type.
+    let after = source_after(bytes, closing);
+    if after.starts_with(b"=>") {
+        GroupKind::Other
+    } else if after.starts_with(b":") {
+        GroupKind::FollowedByColon(bytes.len() - after.len())
+    } else {
+        GroupKind::Cast
+    }
+}
+
+/// Blank out the type of a cast, along with the casts whose type it contains.
+fn blank_cast(
+    bytes: &mut [u8],
+    pending: &mut HashMap<usize, std::ops::Range<usize>>,
+    cast: std::ops::Range<usize>,
+) {
+    let mut colon = cast.start;
+    blank(bytes, cast);
+
+    while let Some(inner) = pending.remove(&colon) {
+        colon = inner.start;
+        blank(bytes, inner);
+    }
+}
+
+fn blank(bytes: &mut [u8], range: std::ops::Range<usize>) {
+    for byte in &mut bytes[range] {
+        if *byte != b'\n' && *byte != b'\r' {
+            *byte = b' ';
+        }
+    }
+}
+
+fn skip_whitespace(bytes: &[u8], index: usize) -> usize {
+    bytes[index.min(bytes.len())..]
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .map_or(bytes.len(), |offset| index + offset)
+}
+
+/// Get the source before a position without trailing whitespace.
+fn source_before(bytes: &[u8], position: usize) -> &[u8] {
+    let end = bytes[..position]
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+        .map_or(0, |index| index + 1);
+
+    &bytes[..end]
+}
+
+/// Get the source after a position without leading whitespace.
+fn source_after(bytes: &[u8], position: usize) -> &[u8] {
+    &bytes[skip_whitespace(bytes, position + 1)..]
+}
+
+/// Check if a keyword at the end of a source is only the end of a longer identifier.
+fn is_word_end(source: &[u8], keyword: &str) -> bool {
+    source.len() > keyword.len() && is_identifier_byte(source[source.len() - keyword.len() - 1])
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$') || !byte.is_ascii()
+}
+
+/// Mark bytes which are code, rather than strings or comments.
+fn get_code_mask(bytes: &[u8]) -> Vec<bool> {
+    let mut is_code = vec![true; bytes.len()];
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let end = match (bytes[index], bytes.get(index + 1)) {
+            (b'/', Some(b'/')) => find_from(bytes, index + 2, b"\n").unwrap_or(bytes.len()),
+            (b'/', Some(b'*')) => {
+                find_from(bytes, index + 2, b"*/").map_or(bytes.len(), |end| end + 2)
+            }
+            (quote @ (b'"' | b'\'' | b'`'), _) => {
+                let mut end = index + 1;
+                while end < bytes.len() && bytes[end] != quote {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                (end + 1).min(bytes.len())
+            }
+            _ => {
+                index += 1;
+                continue;
+            }
+        };
+
+        for is_code in &mut is_code[index..end] {
+            *is_code = false;
+        }
+        index = end;
+    }
+
+    is_code
+}
+
+fn find_from(bytes: &[u8], start: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(start..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|index| start + index)
+}
@@ -0,0 +1,208 @@
+use eyre::WrapErr;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use relative_path::RelativePathBuf;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::lockfile::Lockfile;
+use crate::package::Package;
+use crate::resolver::Resolver;
+use crate::tsconfig::TsConfig;
+use crate::util::build_walker::build_walker;
+use crate::util::is_core_module::{parse_node_version, NodeVersion};
+use crate::util::is_module::is_module;
+use crate::util::load_module::load_module;
+
+/// A package checked against its own package json, either the root package
+/// or a member of its workspaces.
+#[derive(Clone, Debug)]
+pub struct Workspace {
+    /// The workspace directory relative to the root directory.
+    pub path: RelativePathBuf,
+    /// The config with the workspace directory.
+    pub config: Config,
+    pub package: Package,
+    pub tsconfig: TsConfig,
+    pub lockfile: Lockfile,
+    pub resolver: Resolver,
+    /// The lowest Node version of the workspace, from the config or else from `engines.node`.
+    pub node_version: Option<NodeVersion>,
+}
+
+impl Workspace {
+    /// Load a workspace of a directory relative to the directory of a config.
+    pub fn new(config: &Config, path: RelativePathBuf) -> eyre::Result<Self> {
+        let directory = path.to_path(config.get_directory());
+
+        let package = load_module(&directory)
+            .wrap_err_with(|| format!("Failed to read package json from {:?}", directory))?;
+
+        log::debug!("loaded package json {:#?}", package);
+
+        let tsconfig = TsConfig::from_directory(&directory).unwrap_or_default();
+        let lockfile = Lockfile::from_directory(&directory).unwrap_or_default();
+        let resolver = Resolver::from_directory(&directory);
+        let node_version = config
+            .node_version()
+            .or_else(|| {
+                package
+                    .engines
+                    .as_ref()
+                    .and_then(|engines| engines.get("node"))
+                    .and_then(Value::as_str)
+            })
+            .and_then(parse_node_version);
+        let config = config.clone().with_directory(directory);
+
+        Ok(Workspace {
+            path,
+            config,
+            package,
+            tsconfig,
+            lockfile,
+            resolver,
+            node_version,
+        })
+    }
+
+    pub fn get_directory(&self) -> &Path {
+        self.config.get_directory()
+    }
+
+    /// Get a name of the workspace for results, `.` for the root package.
+    pub fn get_name(&self) -> String {
+        if self.path.as_str().is_empty() {
+            String::from(".")
+        } else {
+            self.path.to_string()
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+/// Get directories of workspace members relative to the root directory,
+/// declared by `workspaces` of the package json or by `pnpm-workspace.yaml`.
+///
+/// Only the directories the patterns name are walked, skipping the files ignored by the config.
+pub fn find_workspace_members(
+    config: &Config,
+    package: &Package,
+) -> eyre::Result<Vec<RelativePathBuf>> {
+    let directory = config.get_directory();
+
+    let mut patterns = package
+        .workspaces
+        .as_ref()
+        .map(|workspaces| workspaces.get_packages().to_vec())
+        .unwrap_or_default();
+
+    let pnpm_workspace_path = directory.join("pnpm-workspace.yaml");
+    if pnpm_workspace_path.is_file() {
+        let content = fs::read_to_string(&pnpm_workspace_path)?;
+        let pnpm_workspace: PnpmWorkspace = serde_yaml::from_str(&content)
+            .wrap_err_with(|| format!("Failed to read {:?}", pnpm_workspace_path))?;
+        patterns.extend(pnpm_workspace.packages);
+    }
+
+    let (exclude_patterns, include_patterns): (Vec<_>, Vec<_>) = patterns
+        .iter()
+        .map(|pattern| pattern.trim_start_matches("./").trim_end_matches('/'))
+        .partition(|pattern| pattern.starts_with('!'));
+
+    let include = build_glob_set(include_patterns.iter().copied())?;
+    let exclude = build_glob_set(
+        exclude_patterns
+            .into_iter()
+            .map(|pattern| pattern.trim_start_matches('!')),
+    )?;
+
+    let mut members = BTreeSet::new();
+
+    for pattern in include_patterns {
+        let (base, max_depth) = get_pattern_base(pattern);
+        let base = directory.join(base);
+        if !base.is_dir() {
+            continue;
+        }
+
+        let walker = build_walker(config, &base)?
+            .max_depth(max_depth)
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .build();
+
+        members.extend(
+            walker
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| {
+                    entry
+                        .file_type()
+                        .map_or(false, |file_type| file_type.is_dir())
+                })
+                .filter(|entry| is_module(entry.path()))
+                .filter_map(|entry| {
+                    let path = entry.path().strip_prefix(directory).ok()?;
+                    RelativePathBuf::from_path(path).ok()
+                })
+                .filter(|path| !path.as_str().is_empty())
+                .filter(|path| include.is_match(path.as_str()) && !exclude.is_match(path.as_str())),
+        );
+    }
+
+    Ok(members.into_iter().collect())
+}
+
+/// Get the directory a workspace pattern names before its first glob, with the depth
+/// of directories the pattern matches below it, e.g. `packages` and `1` for `packages/*`.
+/// Patterns with `**` match directories of any depth.
+fn get_pattern_base(pattern: &str) -> (String, Option<usize>) {
+    let components: Vec<_> = pattern.split('/').collect();
+    let glob_index = components
+        .iter()
+        .position(|component| component.contains(['*', '?', '[', '{']))
+        .unwrap_or(components.len());
+
+    let (base, globs) = components.split_at(glob_index);
+    let max_depth = if globs.iter().any(|component| component.contains("**")) {
+        None
+    } else {
+        Some(globs.len())
+    };
+
+    (base.join("/"), max_depth)
+}
+
+fn build_glob_set<'a>(patterns: impl IntoIterator<Item = &'a str>) -> eyre::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .wrap_err_with(|| format!("Malformed workspace pattern: {pattern}"))?;
+        builder.add(glob);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Get the workspace a file belongs to with its index,
+/// which is the one with the deepest directory containing the file.
+pub fn find_workspace<'a>(
+    workspaces: &'a [Workspace],
+    path: &Path,
+) -> Option<(usize, &'a Workspace)> {
+    workspaces
+        .iter()
+        .enumerate()
+        .filter(|(_, workspace)| path.starts_with(workspace.get_directory()))
+        .max_by_key(|(_, workspace)| workspace.get_directory().components().count())
+}
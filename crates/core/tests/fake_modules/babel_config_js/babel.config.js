@@ -0,0 +1,10 @@
+const presets = ['@babel/env', '@foo'];
+
+module.exports = function (api) {
+  api.cache(true);
+
+  return {
+    presets,
+    plugins: ['@foo/bar'],
+  };
+};
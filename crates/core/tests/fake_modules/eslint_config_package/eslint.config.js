@@ -0,0 +1,11 @@
+const js = require('@eslint/js');
+
+module.exports = [
+  js.configs.recommended,
+  {
+    files: ['**/*.js'],
+    rules: {
+      'no-console': 'warn',
+    },
+  },
+];
@@ -0,0 +1,18 @@
+// @flow
+import type { Node } from 'react';
+
+function pick<T: { id: string }, K: $Keys<T> = $Keys<T>>(item: T, key: K): $ElementType<T, K> {
+  return item[key];
+}
+
+const wrap = <T: Object>(value: T): Array<T> => [value];
+
+class Store<+S: { [key: string]: mixed }> {
+  state: S;
+}
+
+const lazy = (((require('cast-dep'): any): mixed): { run: () => void });
+
+export default function render(): Node {
+  return pick(wrap(new Store())[0], 'state');
+}
@@ -0,0 +1,18 @@
+/**
+ * @flow strict
+ */
+import type { Node } from 'react';
+import typeof TypedLib from 'typed-lib';
+import format from 'flow-dep';
+
+opaque type ID = string;
+
+type Props = {|
+  +id: ID,
+  +label: ?string,
+  -items: Array<?number>,
+|};
+
+export default function render(props: Props): Node {
+  return format(props.label);
+}
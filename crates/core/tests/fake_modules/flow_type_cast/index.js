@@ -0,0 +1,15 @@
+// @flow
+import format from 'flow-dep';
+
+const lazy = ((require('cast-dep'): any): { run: () => void });
+
+const identity = <T>(value: T): T => value;
+
+function first<T>(items: Array<T>): ?T {
+  return (items[0]: any);
+}
+
+export default function render(label: mixed): string {
+  lazy.run();
+  return format((identity(label): string), (first([1, 2]): ?number));
+}
@@ -0,0 +1,23 @@
+// @flow
+import type { Node } from 'react';
+
+type OwnProps = {| +id: string |};
+
+type Props = {
+  ...OwnProps,
+  ...$Exact<{ label: string }>,
+  onClick: (...args: Array<mixed>) => void,
+  ...
+};
+
+export opaque type ID: string = string;
+
+declare export opaque type Token: string;
+
+declare export function format(props: {| ...Props, extra: number |}): Node;
+
+export { default as Button } from 'button-dep';
+
+const defaults = { ...{ label: '' }, onClick() {} };
+
+export default require('spread-dep')(defaults);
@@ -0,0 +1,6 @@
+import type { Options } from 'flow-types-dep';
+import format from 'flow-dep';
+
+const options: ?Options = null;
+
+export default format(options);
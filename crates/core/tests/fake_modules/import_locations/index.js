@@ -0,0 +1,8 @@
+import React from "react";
+export { default as chalk } from "chalk";
+
+const debug = require("debug");
+
+export function load() {
+  return import("moment");
+}
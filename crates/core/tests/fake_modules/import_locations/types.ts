@@ -0,0 +1,3 @@
+import type { LoDashStatic } from "lodash";
+
+export type Lodash = LoDashStatic;
@@ -0,0 +1,8 @@
+import fs from 'node:fs';
+import { describe } from 'node:test';
+import { DatabaseSync } from 'node:sqlite';
+import { readFile } from 'fs/promises';
+import diagnostics from 'diagnostics_channel';
+import test from 'test';
+
+export { fs, describe, DatabaseSync, readFile, diagnostics, test };
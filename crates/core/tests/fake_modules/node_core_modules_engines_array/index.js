@@ -0,0 +1,5 @@
+import fs from 'node:fs';
+import { describe } from 'node:test';
+import test from 'test';
+
+export { fs, describe, test };
@@ -0,0 +1,3 @@
+const express = require('express');
+const debug = require('debug');
+const lodash = require('lodash');
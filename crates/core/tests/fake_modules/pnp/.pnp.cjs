@@ -0,0 +1,171 @@
+#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{\
+  "__info": [\
+    "This file is automatically generated. Do not touch it, or risk",\
+    "your modifications being lost."\
+  ],\
+  "dependencyTreeRoots": [\
+    {\
+      "name": "pnp",\
+      "reference": "workspace:."\
+    }\
+  ],\
+  "enableTopLevelFallback": true,\
+  "ignorePatternData": null,\
+  "fallbackExclusionList": [],\
+  "fallbackPool": [],\
+  "packageRegistryData": [\
+    [\
+      null,\
+      [\
+        [\
+          null,\
+          {\
+            "packageLocation": "./",\
+            "packageDependencies": [\
+              [\
+                "anybin",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "host",\
+                "npm:0.0.1"\
+              ],\
+              [\
+                "nobin",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "peer",\
+                "npm:0.0.1"\
+              ]\
+            ],\
+            "linkType": "SOFT"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "anybin",\
+      [\
+        [\
+          "npm:1.0.0",\
+          {\
+            "packageLocation": "./.yarn/unplugged/anybin-npm-1.0.0-5f2c1a9d3e/node_modules/anybin/",\
+            "packageDependencies": [\
+              [\
+                "anybin",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "host",\
+      [\
+        [\
+          "npm:0.0.1",\
+          {\
+            "packageLocation": "./.yarn/cache/host-npm-0.0.1-0b8e1c2d4f.zip/node_modules/host/",\
+            "packageDependencies": [\
+              [\
+                "host",\
+                "npm:0.0.1"\
+              ],\
+              [\
+                "peer",\
+                null\
+              ]\
+            ],\
+            "packagePeers": [\
+              "peer"\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "nobin",\
+      [\
+        [\
+          "npm:1.0.0",\
+          {\
+            "packageLocation": "./.yarn/cache/nobin-npm-1.0.0-7a3d9e6b21.zip/node_modules/nobin/",\
+            "packageDependencies": [\
+              [\
+                "nobin",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "peer",\
+      [\
+        [\
+          "npm:0.0.1",\
+          {\
+            "packageLocation": "./.yarn/cache/peer-npm-0.0.1-c4e2f8a1b9.zip/node_modules/peer/",\
+            "packageDependencies": [\
+              [\
+                "peer",\
+                "npm:0.0.1"\
+              ]\
+            ],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "pnp",\
+      [\
+        [\
+          "workspace:.",\
+          {\
+            "packageLocation": "./",\
+            "packageDependencies": [\
+              [\
+                "anybin",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "host",\
+                "npm:0.0.1"\
+              ],\
+              [\
+                "nobin",\
+                "npm:1.0.0"\
+              ],\
+              [\
+                "peer",\
+                "npm:0.0.1"\
+              ],\
+              [\
+                "pnp",\
+                "workspace:."\
+              ]\
+            ],\
+            "linkType": "SOFT"\
+          }\
+        ]\
+      ]\
+    ]\
+  ],\
+  "packageLocatorsByLocations": {}\
+}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+  return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {basePath: basePath || __dirname});
+}
@@ -0,0 +1,2 @@
+require('parent');
+require('host');
@@ -0,0 +1,4 @@
+import lodash from 'lodash';
+import { format } from '@acme/my-lib/utils';
+
+export { lodash, format };
@@ -0,0 +1,4 @@
+const lib = require('@acme/my-lib');
+
+exports.format = (value) => value.trim();
+exports.lib = lib;
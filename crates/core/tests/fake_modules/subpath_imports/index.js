@@ -0,0 +1,6 @@
+import { format } from '#internal/utils';
+import dep from '#dep';
+import unmapped from '#unmapped';
+import lodash from 'lodash';
+
+export { format, dep, unmapped, lodash };
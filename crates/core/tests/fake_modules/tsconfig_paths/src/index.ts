@@ -0,0 +1,7 @@
+import React from 'react';
+import { format } from '@app/utils';
+import Button from '~/components/Button';
+import config from 'config';
+import Link from 'components/Link';
+
+export { React, format, Button, config, Link };
@@ -0,0 +1,5 @@
+import React from 'react';
+import { debounce } from 'lodash';
+import { Env } from 'env';
+
+export { React, debounce, Env };
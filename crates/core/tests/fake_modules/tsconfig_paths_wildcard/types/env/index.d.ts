@@ -0,0 +1 @@
+export type Env = 'development' | 'production';
@@ -0,0 +1 @@
+import 'raw!./template.html';
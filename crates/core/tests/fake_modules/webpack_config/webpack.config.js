@@ -0,0 +1,27 @@
+const path = require('path');
+
+module.exports = {
+  entry: './index.js',
+  output: {
+    path: path.resolve(__dirname, 'dist'),
+  },
+  module: {
+    rules: [
+      {
+        test: /\.css$/,
+        use: ['style-loader', { loader: 'css-loader', options: { modules: true } }],
+      },
+      {
+        test: /\.svg$/,
+        oneOf: [
+          { resourceQuery: /inline/, loader: require.resolve('svg-inline-loader') },
+          { loader: 'url?limit=8192' },
+        ],
+      },
+      {
+        test: /\.less$/,
+        loaders: 'style!less-loader',
+      },
+    ],
+  },
+};
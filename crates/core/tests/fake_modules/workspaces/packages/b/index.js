@@ -0,0 +1 @@
+import express from 'express';
@@ -0,0 +1,3 @@
+import shared from '@org/shared';
+import utils from '@org/utils';
+import lodash from 'lodash';
@@ -3,7 +3,7 @@ use std::env;
 use std::path::PathBuf;
 
 use depckeck_rs_core::checker::Checker;
-use depckeck_rs_core::checker_result::CheckerResult;
+use depckeck_rs_core::checker_result::{CheckerResult, ImportKind, ImportLocation};
 use depckeck_rs_core::config::Config;
 use pretty_assertions::assert_eq;
 
@@ -11,6 +11,7 @@ use pretty_assertions::assert_eq;
 struct ExpectedCheckResult {
     using_dependencies: BTreeMap<String, HashSet<String>>,
     missing_dependencies: BTreeMap<String, HashSet<String>>,
+    phantom_dependencies: BTreeMap<String, HashSet<String>>,
     unused_dependencies: HashSet<String>,
     unused_dev_dependencies: HashSet<String>,
 }
@@ -18,6 +19,7 @@ struct ExpectedCheckResult {
 fn assert_result(actual: CheckerResult, expected: ExpectedCheckResult) {
     assert_eq!(actual.using_dependencies, expected.using_dependencies);
     assert_eq!(actual.missing_dependencies, expected.missing_dependencies);
+    assert_eq!(actual.phantom_dependencies, expected.phantom_dependencies);
     assert_eq!(actual.unused_dependencies, expected.unused_dependencies);
     assert_eq!(
         actual.unused_dev_dependencies,
@@ -213,6 +215,70 @@ fn test_import_function_webpack() {
     assert_result(actual, expected);
 }
 
+#[test]
+fn test_webpack_inline_loader() {
+    init();
+    let path = get_module_path("webpack_inline_loader");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("another-loader"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("script-loader"),
+                [String::from("index.js"), String::from("index.ts")].into(),
+            ),
+            (
+                String::from("slick-carousel"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("slickity-slick"),
+                [String::from("index.ts")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([
+            (
+                String::from("another-loader"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("script-loader"),
+                [String::from("index.js"), String::from("index.ts")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_webpack_loader_with_parameters() {
+    init();
+    let path = get_module_path("webpack_loader_with_parameters");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("file-loader"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
 #[test]
 fn test_require_resolve_missing() {
     init();
@@ -483,7 +549,6 @@ fn test_good_es7() {
 }
 
 #[test]
-#[ignore]
 fn test_good_es7_flow() {
     init();
     let path = get_module_path("good_es7_flow");
@@ -503,6 +568,121 @@ fn test_good_es7_flow() {
     assert_result(actual, expected);
 }
 
+#[test]
+fn test_flow_import_type() {
+    init();
+    let path = get_module_path("flow_import_type");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("flow-dep"), [String::from("index.js")].into()),
+            (String::from("react"), [String::from("index.js")].into()),
+            (String::from("typed-lib"), [String::from("index.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_flow_type_cast() {
+    init();
+    let path = get_module_path("flow_type_cast");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("cast-dep"), [String::from("index.js")].into()),
+            (String::from("flow-dep"), [String::from("index.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_flow_bounded_generics() {
+    init();
+    let path = get_module_path("flow_bounded_generics");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("cast-dep"), [String::from("index.js")].into()),
+            (String::from("react"), [String::from("index.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_flow_type_spread() {
+    init();
+    let path = get_module_path("flow_type_spread");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("button-dep"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("react"), [String::from("index.js")].into()),
+            (
+                String::from("spread-dep"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_flow_without_pragma() {
+    init();
+    let path = get_module_path("flow_without_pragma");
+
+    let config = Config::new(path).with_flow(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("flow-dep"), [String::from("index.js")].into()),
+            (
+                String::from("flow-types-dep"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
 #[test]
 fn test_typescript() {
     init();
@@ -589,7 +769,6 @@ fn test_typescript() {
 // },
 
 #[test]
-#[ignore]
 fn test_vue() {
     init();
     let path = get_module_path("vue");
@@ -607,9 +786,1150 @@ fn test_vue() {
                 [String::from("component.vue")].into_iter().collect(),
             ),
             (
-                String::from("vue-dep-2"),
-                [String::from("component.vue")].into_iter().collect(),
+                String::from("vue-dep-2"),
+                [String::from("component.vue")].into_iter().collect(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_vue3() {
+    init();
+    let path = get_module_path("vue3");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("vue"), [String::from("index.js")].into()),
+            (
+                String::from("vue-dep-1"),
+                [String::from("component.vue")].into_iter().collect(),
+            ),
+            (
+                String::from("vue-dep-2"),
+                [String::from("component.vue")].into_iter().collect(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_vue_setup() {
+    init();
+    let path = get_module_path("vue_setup");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("vue"), [String::from("component.vue")].into()),
+            (
+                String::from("vue-dep-1"),
+                [String::from("component.vue")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_vue_script_tags() {
+    init();
+    let path = get_module_path("vue_script_tags");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("vue-dep-1"),
+                [String::from("component.vue")].into(),
+            ),
+            (
+                String::from("vue-dep-2"),
+                [String::from("component.vue")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_svelte() {
+    init();
+    let path = get_module_path("svelte");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("dont-find-me"), String::from("svelte")].into(),
+        using_dependencies: BTreeMap::from([(
+            String::from("find-me"),
+            [String::from("App.svelte")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_svelte_ts() {
+    init();
+    let path = get_module_path("svelte_ts");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("instance-dep"),
+                [String::from("Counter.svelte")].into(),
+            ),
+            (
+                String::from("module-dep"),
+                [String::from("Counter.svelte")].into(),
+            ),
+            (
+                String::from("svelte"),
+                [String::from("Counter.svelte")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_sass() {
+    init();
+    let path = get_module_path("sass");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-sass-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@scss-deps/fonts"),
+                [String::from("scss.scss")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile"),
+                [String::from("sass2.sass")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile2"),
+                [String::from("scss2.scss")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile3"),
+                [String::from("scss2.scss")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile4"),
+                [String::from("scss2.scss")].into(),
+            ),
+            (
+                String::from("sass-dep"),
+                [String::from("sass.sass"), String::from("sass2.sass")].into(),
+            ),
+            (
+                String::from("sass-dep2"),
+                [String::from("sass.sass"), String::from("sass2.sass")].into(),
+            ),
+            (String::from("scss-dep"), [String::from("scss.scss")].into()),
+            (
+                String::from("scss-dep-2"),
+                [String::from("scss.scss")].into(),
+            ),
+            (
+                String::from("scss-dep-3"),
+                [String::from("scss.scss")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([
+            (
+                String::from("@test-dep/aFile"),
+                [String::from("sass2.sass")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile2"),
+                [String::from("scss2.scss")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile3"),
+                [String::from("scss2.scss")].into(),
+            ),
+            (
+                String::from("@test-dep/aFile4"),
+                [String::from("scss2.scss")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_css() {
+    init();
+    let path = get_module_path("css");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        unused_dependencies: [String::from("unused-style-dep")].into(),
+        using_dependencies: BTreeMap::from([
+            (String::from("antd"), [String::from("styles.less")].into()),
+            (
+                String::from("font-pack"),
+                [String::from("index.css")].into(),
+            ),
+            (
+                String::from("icon-pack"),
+                [String::from("index.css")].into(),
+            ),
+            (
+                String::from("less-dep"),
+                [String::from("styles.less")].into(),
+            ),
+            (
+                String::from("normalize.css"),
+                [String::from("index.css")].into(),
+            ),
+            (
+                String::from("remote-font"),
+                [String::from("index.css")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_subpath_imports() {
+    init();
+    let path = get_module_path("subpath_imports");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("dep-node-native"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_node_core_modules() {
+    init();
+    let path = get_module_path("node_core_modules");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("diagnostics_channel"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("node:sqlite"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("node:test"), [String::from("index.js")].into()),
+            (String::from("test"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([
+            (
+                String::from("diagnostics_channel"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("node:sqlite"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("node:test"), [String::from("index.js")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_node_core_modules_node_version() {
+    init();
+    let path = get_module_path("node_core_modules");
+
+    let config = Config::new(path).with_node_version(Some(String::from("20")));
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("node:sqlite"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("test"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("node:sqlite"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_node_core_modules_node_version_range() {
+    init();
+    let path = get_module_path("node_core_modules");
+
+    let config = Config::new(path).with_node_version(Some(String::from("^22.5 || >= 23 <25")));
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("test"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_node_core_modules_engines_array() {
+    init();
+    let path = get_module_path("node_core_modules_engines_array");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("test"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_self_reference() {
+    init();
+    let path = get_module_path("self_reference");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_workspaces() {
+    init();
+    let path = get_module_path("workspaces");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let mut actual = checker.check_workspaces().unwrap().workspaces;
+
+    assert_eq!(
+        actual.keys().collect::<Vec<_>>(),
+        [".", "packages/a", "packages/b"]
+    );
+
+    let expected = ExpectedCheckResult {
+        unused_dev_dependencies: [String::from("prettier")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual.remove(".").unwrap(), expected);
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("react")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual.remove("packages/a").unwrap(), expected);
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("express"),
+            [String::from("index.js")].into(),
+        )]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("express"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual.remove("packages/b").unwrap(), expected);
+}
+
+#[test]
+fn test_workspaces_pnpm() {
+    init();
+    let path = get_module_path("workspaces_pnpm");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let mut actual = checker.check_workspaces().unwrap().workspaces;
+
+    assert_eq!(actual.keys().collect::<Vec<_>>(), [".", "apps/web"]);
+
+    assert_result(actual.remove(".").unwrap(), ExpectedCheckResult::default());
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("react"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual.remove("apps/web").unwrap(), expected);
+}
+
+#[test]
+fn test_workspaces_hoisted() {
+    init();
+    let path = get_module_path("workspaces_hoisted");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_workspaces().unwrap();
+
+    let mut workspaces = actual.workspaces;
+
+    // Root dependencies used by members are hoisted, so only typescript is unused.
+    let expected = ExpectedCheckResult {
+        unused_dev_dependencies: [String::from("typescript")].into(),
+        ..Default::default()
+    };
+
+    assert_result(workspaces.remove(".").unwrap(), expected);
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@org/shared"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("@org/utils"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("@org/utils"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(workspaces.remove("packages/app").unwrap(), expected);
+}
+
+#[test]
+fn test_phantom_npm() {
+    init();
+    let path = get_module_path("phantom_npm");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("debug"), [String::from("index.js")].into()),
+            (String::from("express"), [String::from("index.js")].into()),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        phantom_dependencies: BTreeMap::from([(
+            String::from("debug"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_phantom_yarn() {
+    init();
+    let path = get_module_path("phantom_yarn");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("debug"), [String::from("index.js")].into()),
+            (String::from("express"), [String::from("index.js")].into()),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        phantom_dependencies: BTreeMap::from([(
+            String::from("debug"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_phantom_pnpm() {
+    init();
+    let path = get_module_path("phantom_pnpm");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("debug"), [String::from("index.js")].into()),
+            (String::from("express"), [String::from("index.js")].into()),
+            (String::from("lodash"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("lodash"),
+            [String::from("index.js")].into(),
+        )]),
+        phantom_dependencies: BTreeMap::from([(
+            String::from("debug"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_missing() {
+    init();
+    let path = get_module_path("missing");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_missing_nested() {
+    init();
+    let path = get_module_path("missing_nested");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("outer-missing-dep"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("used-dep"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("outer-missing-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_missing_peer_deps() {
+    init();
+    let path = get_module_path("missing_peer_deps");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("missing-this-dep"),
+                [String::from("index.js")].into(),
+            ),
+            (String::from("peer-dep"), [String::from("index.js")].into()),
+            (
+                String::from("optional-dep"),
+                [String::from("index.js")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("missing-this-dep"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+#[ignore]
+fn test_grunt() {
+    init();
+    let path = get_module_path("grunt");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("grunt-contrib-jshint"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+#[ignore]
+fn test_grunt_tasks() {
+    init();
+    let path = get_module_path("grunt-tasks");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("grunt-contrib-jshint"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_babel_config() {
+    init();
+    let path = get_module_path("babel_config");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@babel/plugin-transform-runtime"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("@babel/preset-react"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("@babel/preset-typescript"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("babel-plugin-istanbul"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("babel-plugin-module-resolver"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("babel-preset-env"),
+                [String::from(".babelrc")].into(),
+            ),
+            (
+                String::from("custom-plugin"),
+                [String::from(".babelrc")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("@babel/core")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_babel_config_js() {
+    init();
+    let path = get_module_path("babel_config_js");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@babel/preset-env"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("@foo/babel-plugin-bar"),
+                [String::from("babel.config.js")].into(),
+            ),
+            (
+                String::from("@foo/babel-preset"),
+                [String::from("babel.config.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_babel_package() {
+    init();
+    let path = get_module_path("babel_package");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@babel/plugin-proposal-decorators"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("babel-preset-react-app"),
+                [String::from("package.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("babel-preset-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_eslint_config() {
+    init();
+    let path = get_module_path("eslint_config");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("eslint-config-preset"),
+            [String::from(".eslintrc")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("eslint-config-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_eslint_config_js() {
+    init();
+    let path = get_module_path("eslint_config_js");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("eslint"), [String::from("lint")].into()),
+            (
+                String::from("eslint-config-foo-bar"),
+                [String::from("config.js")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_eslint_config_custom() {
+    init();
+    let path = get_module_path("eslint_config_custom");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("eslint"), [String::from("lint")].into()),
+            (
+                String::from("eslint-config-foo-bar"),
+                [String::from("config.json")].into(),
+            ),
+            (
+                String::from("eslint-plugin-ignored"),
+                [String::from("config.json")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("eslint-plugin-ignored"),
+            [String::from("config.json")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_eslint_config_shorthand() {
+    init();
+    let path = get_module_path("eslint_config_shorthand");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@bar/eslint-plugin-baz"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("@foo/eslint-config"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("@typescript-eslint/eslint-plugin"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("@typescript-eslint/parser"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("eslint-config-airbnb"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("eslint-plugin-react"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+            (
+                String::from("vue-eslint-parser"),
+                [String::from(".eslintrc.yml")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("eslint-config-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_eslint_config_package() {
+    init();
+    let path = get_module_path("eslint_config_package");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@eslint/js"),
+                [String::from("eslint.config.js")].into(),
+            ),
+            (
+                String::from("eslint-config-local"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("eslint-config-next"),
+                [String::from("package.json")].into(),
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_jest_config() {
+    init();
+    let path = get_module_path("jest_config");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@testing-library/jest-dom"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("babel-jest"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("enzyme-to-json"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("jest-environment-jsdom"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("jest-junit"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("jest-svg-transformer"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("jest-watch-select-projects"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("jest-watch-typeahead"),
+                [String::from("jest.config.json")].into(),
+            ),
+            (
+                String::from("ts-jest"),
+                [String::from("jest.config.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("jest")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_jest_package() {
+    init();
+    let path = get_module_path("jest_package");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@happy-dom/jest-environment"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("jest-environment-node"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("unknown-plugin"),
+                [String::from("package.json")].into(),
+            ),
+        ]),
+        missing_dependencies: BTreeMap::from([
+            (
+                String::from("@happy-dom/jest-environment"),
+                [String::from("package.json")].into(),
+            ),
+            (
+                String::from("unknown-plugin"),
+                [String::from("package.json")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("jest"), String::from("jest-runner-unused")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_jest_jsdom() {
+    init();
+    let path = get_module_path("jest_jsdom");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("jest-environment-jsdom"),
+            [String::from("package.json")].into(),
+        )]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("jest-environment-jsdom"),
+            [String::from("package.json")].into(),
+        )]),
+        unused_dev_dependencies: [String::from("jest")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_mocha_config() {
+    init();
+    let path = get_module_path("mocha_config");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("babel"), [String::from("config.mocha")].into()),
+            (String::from("chai"), [String::from("config.mocha")].into()),
+            (String::from("mocha"), [String::from("test")].into()),
+        ]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_mocha_opts() {
+    init();
+    let path = get_module_path("mocha_opts");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("babel"),
+                [String::from("mocha.opts.txt")].into(),
+            ),
+            (
+                String::from("chai"),
+                [String::from("mocha.opts.txt")].into(),
             ),
+            (String::from("mocha"), [String::from("test")].into()),
         ]),
         ..Default::default()
     };
@@ -618,28 +1938,42 @@ fn test_vue() {
 }
 
 #[test]
-#[ignore]
-fn test_vue3() {
+fn test_mocha_rc() {
     init();
-    let path = get_module_path("vue3");
+    let path = get_module_path("mocha_rc");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
     let actual = checker.check_package().unwrap();
 
     let expected = ExpectedCheckResult {
-        unused_dependencies: [String::from("unused-dep")].into(),
         using_dependencies: BTreeMap::from([
-            (String::from("vue"), [String::from("index.js")].into()),
             (
-                String::from("vue-dep-1"),
-                [String::from("component.vue")].into_iter().collect(),
+                String::from("@babel/register"),
+                [String::from("package.json")].into(),
             ),
             (
-                String::from("vue-dep-2"),
-                [String::from("component.vue")].into_iter().collect(),
+                String::from("mocha-typescript"),
+                [String::from(".mocharc.yml")].into(),
+            ),
+            (
+                String::from("mochawesome"),
+                [String::from(".mocharc.yml")].into(),
+            ),
+            (
+                String::from("shared-tests"),
+                [String::from(".mocharc.yml")].into(),
+            ),
+            (
+                String::from("should"),
+                [String::from("test/mocha.opts")].into(),
+            ),
+            (
+                String::from("ts-node"),
+                [String::from(".mocharc.yml")].into(),
             ),
         ]),
+        unused_dev_dependencies: [String::from("mocha")].into(),
         ..Default::default()
     };
 
@@ -647,23 +1981,35 @@ fn test_vue3() {
 }
 
 #[test]
-fn test_missing() {
+fn test_tsconfig_extends() {
     init();
-    let path = get_module_path("missing");
+    let path = get_module_path("tsconfig_extends");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
     let actual = checker.check_package().unwrap();
 
     let expected = ExpectedCheckResult {
-        using_dependencies: BTreeMap::from([(
-            String::from("missing-dep"),
-            [String::from("index.js")].into(),
-        )]),
-        missing_dependencies: BTreeMap::from([(
-            String::from("missing-dep"),
-            [String::from("index.js")].into(),
-        )]),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@tsconfig/node18"),
+                [String::from("tsconfig.json")].into(),
+            ),
+            (
+                String::from("@types/jest"),
+                [String::from("tsconfig.json")].into(),
+            ),
+            (
+                String::from("@types/node"),
+                [String::from("tsconfig.json")].into(),
+            ),
+            (
+                String::from("typescript-plugin-css-modules"),
+                [String::from("tsconfig.json")].into(),
+            ),
+            (String::from("vite"), [String::from("tsconfig.json")].into()),
+        ]),
+        unused_dev_dependencies: [String::from("@types/unused")].into(),
         ..Default::default()
     };
 
@@ -671,9 +2017,9 @@ fn test_missing() {
 }
 
 #[test]
-fn test_missing_nested() {
+fn test_tsconfig_type_roots() {
     init();
-    let path = get_module_path("missing_nested");
+    let path = get_module_path("tsconfig_type_roots");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
@@ -682,15 +2028,24 @@ fn test_missing_nested() {
     let expected = ExpectedCheckResult {
         using_dependencies: BTreeMap::from([
             (
-                String::from("outer-missing-dep"),
-                [String::from("index.js")].into(),
+                String::from("@company/shared-types"),
+                [String::from("tsconfig.json")].into(),
             ),
-            (String::from("used-dep"), [String::from("index.js")].into()),
+            (
+                String::from("@types/node"),
+                [String::from("tsconfig.json")].into(),
+            ),
+            (
+                String::from("@types/react"),
+                [
+                    String::from("tsconfig.json"),
+                    String::from("tsconfig.build.json"),
+                ]
+                .into(),
+            ),
+            (String::from("typescript"), [String::from("build")].into()),
         ]),
-        missing_dependencies: BTreeMap::from([(
-            String::from("outer-missing-dep"),
-            [String::from("index.js")].into(),
-        )]),
+        unused_dev_dependencies: [String::from("@other/unused")].into(),
         ..Default::default()
     };
 
@@ -698,29 +2053,18 @@ fn test_missing_nested() {
 }
 
 #[test]
-fn test_missing_peer_deps() {
+fn test_tsconfig_paths() {
     init();
-    let path = get_module_path("missing_peer_deps");
+    let path = get_module_path("tsconfig_paths");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
     let actual = checker.check_package().unwrap();
 
     let expected = ExpectedCheckResult {
-        using_dependencies: BTreeMap::from([
-            (
-                String::from("missing-this-dep"),
-                [String::from("index.js")].into(),
-            ),
-            (String::from("peer-dep"), [String::from("index.js")].into()),
-            (
-                String::from("optional-dep"),
-                [String::from("index.js")].into(),
-            ),
-        ]),
-        missing_dependencies: BTreeMap::from([(
-            String::from("missing-this-dep"),
-            [String::from("index.js")].into(),
+        using_dependencies: BTreeMap::from([(
+            String::from("react"),
+            [String::from("src/index.ts")].into(),
         )]),
         ..Default::default()
     };
@@ -729,20 +2073,22 @@ fn test_missing_peer_deps() {
 }
 
 #[test]
-#[ignore]
-fn test_grunt() {
+fn test_tsconfig_paths_wildcard() {
     init();
-    let path = get_module_path("grunt");
+    let path = get_module_path("tsconfig_paths_wildcard");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
     let actual = checker.check_package().unwrap();
 
     let expected = ExpectedCheckResult {
-        using_dependencies: BTreeMap::from([(
-            String::from("grunt-contrib-jshint"),
-            [String::from("index.js")].into(),
-        )]),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("lodash"),
+                [String::from("src/index.ts")].into(),
+            ),
+            (String::from("react"), [String::from("src/index.ts")].into()),
+        ]),
         ..Default::default()
     };
 
@@ -750,20 +2096,42 @@ fn test_grunt() {
 }
 
 #[test]
-#[ignore]
-fn test_grunt_tasks() {
+fn test_webpack_config() {
     init();
-    let path = get_module_path("grunt-tasks");
+    let path = get_module_path("webpack_config");
 
     let config = Config::new(path);
     let checker = Checker::new(config);
     let actual = checker.check_package().unwrap();
 
     let expected = ExpectedCheckResult {
-        using_dependencies: BTreeMap::from([(
-            String::from("grunt-contrib-jshint"),
-            [String::from("index.js")].into(),
-        )]),
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("css-loader"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("less-loader"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("raw-loader"),
+                [String::from("index.js")].into(),
+            ),
+            (
+                String::from("style-loader"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("svg-inline-loader"),
+                [String::from("webpack.config.js")].into(),
+            ),
+            (
+                String::from("url-loader"),
+                [String::from("webpack.config.js")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-loader"), String::from("webpack")].into(),
         ..Default::default()
     };
 
@@ -968,6 +2336,132 @@ fn test_bin_js_ignore_bin_package_false() {
     assert_result(actual, expected);
 }
 
+#[test]
+fn test_pnp() {
+    init();
+    let path = get_module_path("pnp");
+
+    let config = Config::new(path).with_ignore_bin_package(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("host"), [String::from("index.js")].into()),
+            (String::from("peer"), [String::from("index.js")].into()),
+        ]),
+        unused_dependencies: [String::from("nobin")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_pnp_virtual() {
+    init();
+    let path = get_module_path("pnp_virtual");
+
+    let config = Config::new(path).with_ignore_bin_package(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("host"), [String::from("index.js")].into()),
+            (String::from("peer"), [String::from("index.js")].into()),
+        ]),
+        unused_dependencies: [String::from("nobin")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_pnpm_isolated() {
+    init();
+    let path = get_module_path("pnpm_isolated");
+
+    let config = Config::new(path).with_ignore_bin_package(true);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("host"), [String::from("index.js")].into()),
+            (String::from("parent"), [String::from("index.js")].into()),
+            (String::from("peer"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("host"),
+            [String::from("index.js")].into(),
+        )]),
+        unused_dependencies: [String::from("nobin")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
+#[test]
+fn test_install_layouts() {
+    init();
+
+    for layout in ["npm", "pnpm"] {
+        let path = get_module_path("install_layouts").join(layout);
+
+        let config = Config::new(path);
+        let checker = Checker::new(config);
+        let actual = checker.check_package().unwrap();
+
+        let expected = ExpectedCheckResult {
+            using_dependencies: BTreeMap::from([
+                (String::from("host"), [String::from("index.js")].into()),
+                (String::from("parent"), [String::from("index.js")].into()),
+                (String::from("peer"), [String::from("index.js")].into()),
+            ]),
+            missing_dependencies: BTreeMap::from([(
+                String::from("host"),
+                [String::from("index.js")].into(),
+            )]),
+            ..Default::default()
+        };
+
+        assert_result(actual, expected);
+    }
+}
+
+#[test]
+fn test_scripts_bin() {
+    init();
+    let path = get_module_path("scripts_bin");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (String::from("@scope/tool"), [String::from("lint")].into()),
+            (String::from("cross-env"), [String::from("test")].into()),
+            (
+                String::from("jest"),
+                [String::from("release"), String::from("test")].into(),
+            ),
+            (String::from("rimraf"), [String::from("build")].into()),
+            (
+                String::from("typescript"),
+                [String::from("build"), String::from("typecheck")].into(),
+            ),
+        ]),
+        unused_dev_dependencies: [String::from("unused-tool")].into(),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
 #[test]
 fn test_good_ignore_bin_package_true() {
     init();
@@ -1147,6 +2641,26 @@ fn test_jsx() {
     assert_result(actual, expected);
 }
 
+#[test]
+fn test_jsx_flow() {
+    init();
+    let path = get_module_path("jsx_flow");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([(
+            String::from("react"),
+            [String::from("index.jsx")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
+
 #[test]
 fn test_jsx_js() {
     init();
@@ -1307,3 +2821,71 @@ fn test_cjs() {
 
     assert_result(actual, expected);
 }
+
+#[test]
+fn test_import_locations() {
+    init();
+    let path = get_module_path("import_locations");
+
+    let config = Config::new(path);
+    let checker = Checker::new(config);
+    let actual = checker.check_package().unwrap();
+
+    let import_location = |file: &str, line, column, kind| ImportLocation {
+        file: String::from(file),
+        line,
+        column,
+        kind,
+    };
+
+    let import_locations = BTreeMap::from([
+        (
+            String::from("@types/lodash"),
+            [import_location("types.ts", 1, 1, ImportKind::ImportType)].into(),
+        ),
+        (
+            String::from("chalk"),
+            [import_location("index.js", 2, 1, ImportKind::ExportFrom)].into(),
+        ),
+        (
+            String::from("debug"),
+            [import_location("index.js", 4, 15, ImportKind::Require)].into(),
+        ),
+        (
+            String::from("moment"),
+            [import_location(
+                "index.js",
+                7,
+                10,
+                ImportKind::DynamicImport,
+            )]
+            .into(),
+        ),
+        (
+            String::from("react"),
+            [import_location("index.js", 1, 1, ImportKind::Import)].into(),
+        ),
+    ]);
+
+    assert_eq!(actual.import_locations, import_locations);
+
+    let expected = ExpectedCheckResult {
+        using_dependencies: BTreeMap::from([
+            (
+                String::from("@types/lodash"),
+                [String::from("types.ts")].into(),
+            ),
+            (String::from("chalk"), [String::from("index.js")].into()),
+            (String::from("debug"), [String::from("index.js")].into()),
+            (String::from("moment"), [String::from("index.js")].into()),
+            (String::from("react"), [String::from("index.js")].into()),
+        ]),
+        missing_dependencies: BTreeMap::from([(
+            String::from("moment"),
+            [String::from("index.js")].into(),
+        )]),
+        ..Default::default()
+    };
+
+    assert_result(actual, expected);
+}
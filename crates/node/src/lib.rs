@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use depckeck_rs_core::checker::Checker;
-use depckeck_rs_core::checker_result::CheckerResult;
+use depckeck_rs_core::checker_result::{self, CheckerResult, ImportKind, WorkspacesCheckerResult};
 use depckeck_rs_core::config;
 
 #[napi(object)]
@@ -18,22 +18,42 @@ pub struct Options {
     pub ignore_matches: Option<Vec<String>>,
     pub skip_missing: Option<bool>,
     pub ignore_path: Option<String>,
+    pub flow: Option<bool>,
+    pub node_version: Option<String>,
     pub verbose: Option<u32>,
 }
 
+#[napi(object)]
+pub struct ImportLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    /// One of `import`, `require`, `dynamicImport`, `exportFrom` and `importType`.
+    pub kind: String,
+}
+
 #[napi(object)]
 pub struct DepcheckResult {
     pub using_dependencies: HashMap<String, Vec<String>>,
+    pub import_locations: HashMap<String, Vec<ImportLocation>>,
     pub missing_dependencies: HashMap<String, Vec<String>>,
+    pub phantom_dependencies: HashMap<String, Vec<String>>,
     pub unused_dependencies: Vec<String>,
     pub unused_dev_dependencies: Vec<String>,
 }
 
+#[napi(object)]
+pub struct DepcheckWorkspacesResult {
+    pub workspaces: HashMap<String, DepcheckResult>,
+}
+
 impl From<CheckerResult> for DepcheckResult {
     fn from(result: CheckerResult) -> Self {
         let CheckerResult {
             using_dependencies,
+            import_locations,
             missing_dependencies,
+            phantom_dependencies,
             unused_dependencies,
             unused_dev_dependencies,
         } = result;
@@ -43,25 +63,90 @@ impl From<CheckerResult> for DepcheckResult {
             .map(|(dependency, files)| (dependency, files.into_iter().collect()))
             .collect();
 
+        let import_locations = import_locations
+            .into_iter()
+            .map(|(dependency, locations)| {
+                (
+                    dependency,
+                    locations.into_iter().map(ImportLocation::from).collect(),
+                )
+            })
+            .collect();
+
         let missing_dependencies = missing_dependencies
             .into_iter()
             .map(|(dependency, files)| (dependency, files.into_iter().collect()))
             .collect();
 
+        let phantom_dependencies = phantom_dependencies
+            .into_iter()
+            .map(|(dependency, files)| (dependency, files.into_iter().collect()))
+            .collect();
+
         let unused_dependencies = unused_dependencies.into_iter().collect();
         let unused_dev_dependencies = unused_dev_dependencies.into_iter().collect();
 
         DepcheckResult {
             using_dependencies,
+            import_locations,
             missing_dependencies,
+            phantom_dependencies,
             unused_dependencies,
             unused_dev_dependencies,
         }
     }
 }
 
+impl From<checker_result::ImportLocation> for ImportLocation {
+    fn from(location: checker_result::ImportLocation) -> Self {
+        let kind = match location.kind {
+            ImportKind::Import => "import",
+            ImportKind::Require => "require",
+            ImportKind::DynamicImport => "dynamicImport",
+            ImportKind::ExportFrom => "exportFrom",
+            ImportKind::ImportType => "importType",
+        };
+
+        ImportLocation {
+            file: location.file,
+            line: location.line as u32,
+            column: location.column as u32,
+            kind: String::from(kind),
+        }
+    }
+}
+
+impl From<WorkspacesCheckerResult> for DepcheckWorkspacesResult {
+    fn from(result: WorkspacesCheckerResult) -> Self {
+        let workspaces = result
+            .workspaces
+            .into_iter()
+            .map(|(workspace, result)| (workspace, result.into()))
+            .collect();
+
+        DepcheckWorkspacesResult { workspaces }
+    }
+}
+
 #[napi]
 pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
+    let config = create_config(path, options);
+
+    let result = Checker::new(config).check_package().unwrap();
+
+    result.into()
+}
+
+#[napi]
+pub fn depcheck_workspaces(path: String, options: Option<Options>) -> DepcheckWorkspacesResult {
+    let config = create_config(path, options);
+
+    let result = Checker::new(config).check_workspaces().unwrap();
+
+    result.into()
+}
+
+fn create_config(path: String, options: Option<Options>) -> config::Config {
     let path = PathBuf::from(path);
 
     let mut config = config::Config::new(path);
@@ -83,6 +168,14 @@ pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
             config = config.with_skip_missing(skip_missing);
         }
 
+        if let Some(flow) = options.flow {
+            config = config.with_flow(flow);
+        }
+
+        if let Some(node_version) = options.node_version {
+            config = config.with_node_version(Some(node_version));
+        }
+
         let verbose = options.verbose.unwrap_or(0);
         let verbose = match verbose {
             0 => LevelFilter::Error,
@@ -97,7 +190,5 @@ pub fn depcheck(path: String, options: Option<Options>) -> DepcheckResult {
         config = config.with_ignore_path(ignore_path);
     }
 
-    let result = Checker::new(config).check_package().unwrap();
-
-    result.into()
+    config
 }
@@ -4,7 +4,9 @@ test('should not failed', () => {
   const result = depcheck('./crates/core/tests/fake_modules/bad');
   expect(result).toMatchInlineSnapshot(`
 Object {
+  "importLocations": Object {},
   "missingDependencies": Object {},
+  "phantomDependencies": Object {},
   "unusedDependencies": Array [
     "optimist",
   ],
@@ -9,12 +9,27 @@ export interface Options {
   ignoreMatches?: Array<string>
   skipMissing?: boolean
   ignorePath?: string
+  flow?: boolean
+  nodeVersion?: string
   verbose?: number
 }
+export interface ImportLocation {
+  file: string
+  line: number
+  column: number
+  /** One of `import`, `require`, `dynamicImport`, `exportFrom` and `importType`. */
+  kind: string
+}
 export interface DepcheckResult {
   usingDependencies: Record<string, Array<string>>
+  importLocations: Record<string, Array<ImportLocation>>
   missingDependencies: Record<string, Array<string>>
+  phantomDependencies: Record<string, Array<string>>
   unusedDependencies: Array<string>
   unusedDevDependencies: Array<string>
 }
+export interface DepcheckWorkspacesResult {
+  workspaces: Record<string, DepcheckResult>
+}
 export function depcheck(path: string, options?: Options | undefined | null): DepcheckResult
+export function depcheckWorkspaces(path: string, options?: Options | undefined | null): DepcheckWorkspacesResult
@@ -236,6 +236,7 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { depcheck } = nativeBinding
+const { depcheck, depcheckWorkspaces } = nativeBinding
 
 module.exports.depcheck = depcheck
+module.exports.depcheckWorkspaces = depcheckWorkspaces
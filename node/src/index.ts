@@ -1 +1 @@
-export {depcheck, DepcheckResult, Options} from './binding';
+export {depcheck, depcheckWorkspaces, DepcheckResult, DepcheckWorkspacesResult, ImportLocation, Options} from './binding';